@@ -1,9 +1,6 @@
 package cmd
 
 import (
-	"fmt"
-	"os"
-
 	"github.com/benoctopus/sesh/internal/config"
 	"github.com/benoctopus/sesh/internal/display"
 	"github.com/benoctopus/sesh/internal/git"
@@ -109,13 +106,8 @@ func runClone(cmd *cobra.Command, args []string) error {
 
 	// Execute startup command if configured
 	startupCmd, err := config.GetStartupCommand(worktreePath)
-	if err == nil && startupCmd != "" && sessionMgr.Name() == "tmux" {
-		disp.Infof("Running startup command: %s", disp.Faint(startupCmd))
-		if tmuxMgr, ok := sessionMgr.(*session.TmuxManager); ok {
-			if err := tmuxMgr.SendKeys(sessionName, startupCmd); err != nil {
-				fmt.Fprintf(os.Stderr, "Warning: failed to run startup command: %v\n", err)
-			}
-		}
+	if err == nil {
+		sendStartupCommand(disp, sessionMgr, sessionName, startupCmd)
 	}
 
 	// Attach to the new session if not detached
@@ -0,0 +1,152 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+
+	"github.com/benoctopus/sesh/internal/config"
+	"github.com/benoctopus/sesh/internal/display"
+	"github.com/benoctopus/sesh/internal/doctor"
+	"github.com/benoctopus/sesh/internal/models"
+	"github.com/benoctopus/sesh/internal/state"
+	"github.com/rotisserie/eris"
+	"github.com/spf13/cobra"
+)
+
+var doctorCmd = &cobra.Command{
+	Use:   "doctor",
+	Short: "Check sesh directories for ownership and permission problems",
+	Long: `Check that the sesh config, database, workspace, project repositories and
+worktrees are owned by and writable for the current user.
+
+On shared machines, worktrees created under sudo or by another user cause later
+operations to fail with opaque IO errors. For every problem found, doctor
+suggests the chown/chmod command that fixes it.
+
+Examples:
+  sesh doctor`,
+	RunE: runDoctor,
+}
+
+func init() {
+	rootCmd.AddCommand(doctorCmd)
+}
+
+func runDoctor(cmd *cobra.Command, args []string) error {
+	disp := display.NewStderr()
+
+	if os.Geteuid() == 0 {
+		disp.Warning("Running as root: ownership checks are relative to root, run doctor as your normal user")
+	}
+
+	// Load configuration
+	cfg, err := config.LoadConfig()
+	if err != nil {
+		return eris.Wrap(err, "failed to load configuration")
+	}
+
+	var checks []*doctor.PathCheck
+
+	configDir, err := config.GetConfigDir()
+	if err != nil {
+		return eris.Wrap(err, "failed to get config directory")
+	}
+	checks = append(checks, doctor.CheckPath("Config directory", configDir, false))
+
+	configPath, err := config.GetConfigPath()
+	if err != nil {
+		return eris.Wrap(err, "failed to get config path")
+	}
+	checks = append(checks, doctor.CheckPath("Config file", configPath, false))
+
+	dbPath, err := config.GetDBPath()
+	if err != nil {
+		return eris.Wrap(err, "failed to get database path")
+	}
+	checks = append(checks, doctor.CheckPath("Database", dbPath, false))
+
+	workspaceCheck := doctor.CheckPath("Workspace directory", cfg.WorkspaceDir, false)
+	checks = append(checks, workspaceCheck)
+
+	// Only look into projects if the workspace itself is usable
+	if workspaceCheck.OK() && !workspaceCheck.Missing {
+		projects, err := state.DiscoverProjects(cfg.WorkspaceDir)
+		if err != nil {
+			return eris.Wrap(err, "failed to discover projects")
+		}
+
+		for _, proj := range projects {
+			projectCheck := doctor.CheckPath(
+				fmt.Sprintf("Repository %s", proj.Name),
+				proj.LocalPath,
+				true,
+			)
+			checks = append(checks, projectCheck)
+			if !projectCheck.OK() {
+				// git can't list worktrees of a repository it can't read
+				continue
+			}
+
+			worktrees, err := state.DiscoverWorktrees(proj)
+			if err != nil {
+				disp.Warningf("failed to list worktrees for %s: %v", proj.Name, err)
+				continue
+			}
+
+			for _, wt := range worktrees {
+				// The bare repository is listed as a worktree; it was already checked above
+				if isBareRepoEntry(proj, wt) {
+					continue
+				}
+
+				checks = append(checks, doctor.CheckPath(
+					fmt.Sprintf("Worktree %s (%s)", proj.Name, worktreeLabel(wt)),
+					wt.Path,
+					true,
+				))
+			}
+		}
+	}
+
+	problems := printDoctorChecks(disp, checks)
+	if problems > 0 {
+		disp.Println()
+		disp.Errorf("Found %d path(s) with ownership or permission problems", problems)
+		return exitWithStatus(cmd, 1)
+	}
+
+	disp.Println()
+	disp.Success("No ownership or permission problems found")
+	return nil
+}
+
+// isBareRepoEntry reports whether wt is the bare repository entry of 'git worktree list'
+func isBareRepoEntry(proj *models.Project, wt *models.Worktree) bool {
+	return resolvePath(wt.Path) == resolvePath(proj.LocalPath)
+}
+
+// printDoctorChecks prints the result of each check and returns the number of failed checks
+func printDoctorChecks(disp display.Printer, checks []*doctor.PathCheck) int {
+	problems := 0
+
+	disp.Printf("\n%s\n\n", disp.Bold("Ownership and permissions"))
+	for _, check := range checks {
+		switch {
+		case check.Missing:
+			disp.Printf("  %s %s %s\n", disp.Faint("-"), check.Label, disp.Faint("(not created yet)"))
+		case check.OK():
+			disp.Printf("  %s %s\n", disp.SuccessText("✓"), check.Label)
+		default:
+			problems++
+			disp.Printf("  %s %s %s\n", disp.ErrorText("✗"), check.Label, disp.Faint(check.Path))
+			for _, problem := range check.Problems {
+				disp.Printf("      %s\n", problem)
+			}
+			for _, fix := range check.Fixes {
+				disp.Printf("      %s %s\n", disp.InfoText("fix:"), fix)
+			}
+		}
+	}
+
+	return problems
+}
@@ -0,0 +1,30 @@
+package cmd
+
+import (
+	"testing"
+
+	"github.com/benoctopus/sesh/internal/models"
+)
+
+func TestIsBareRepoEntry(t *testing.T) {
+	proj := &models.Project{Name: "repo", LocalPath: "/ws/repo.git"}
+
+	tests := []struct {
+		name string
+		wt   *models.Worktree
+		want bool
+	}{
+		{name: "bare repository", wt: &models.Worktree{Path: "/ws/repo.git"}, want: true},
+		{name: "bare repository with trailing slash", wt: &models.Worktree{Path: "/ws/repo.git/"}, want: true},
+		{name: "branch worktree", wt: &models.Worktree{Branch: "main", Path: "/ws/repo/main"}, want: false},
+		{name: "detached worktree", wt: &models.Worktree{Path: "/ws/repo/v1.2.0"}, want: false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := isBareRepoEntry(proj, tt.wt); got != tt.want {
+				t.Errorf("isBareRepoEntry(%q) = %v, want %v", tt.wt.Path, got, tt.want)
+			}
+		})
+	}
+}
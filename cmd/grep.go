@@ -0,0 +1,200 @@
+package cmd
+
+import (
+	"bufio"
+	"fmt"
+	"io"
+	"os"
+	"os/exec"
+	"strings"
+
+	"github.com/benoctopus/sesh/internal/config"
+	"github.com/benoctopus/sesh/internal/display"
+	"github.com/benoctopus/sesh/internal/models"
+	"github.com/benoctopus/sesh/internal/project"
+	"github.com/benoctopus/sesh/internal/state"
+	"github.com/rotisserie/eris"
+	"github.com/spf13/cobra"
+)
+
+var (
+	grepProjectName string
+	grepIgnoreCase  bool
+)
+
+var grepCmd = &cobra.Command{
+	Use:   "grep <pattern> [-p project]",
+	Short: "Search across all worktrees",
+	Long: `Search for a pattern across all worktrees of a project, or of all projects.
+
+Each result is prefixed with project:branch so you can find which branch
+contains a given change. Uses ripgrep (rg) when available and falls back
+to git grep otherwise.
+
+Examples:
+  sesh grep "TODO"                      # Search all worktrees of all projects
+  sesh grep -p myproject "func main"    # Search worktrees of one project
+  sesh grep -i "deprecated"             # Case-insensitive search`,
+	Args: cobra.ExactArgs(1),
+	RunE: runGrep,
+}
+
+func init() {
+	rootCmd.AddCommand(grepCmd)
+	grepCmd.Flags().
+		StringVarP(&grepProjectName, "project", "p", "", "Search only the worktrees of this project")
+	grepCmd.Flags().
+		BoolVarP(&grepIgnoreCase, "ignore-case", "i", false, "Search case-insensitively")
+}
+
+func runGrep(cmd *cobra.Command, args []string) error {
+	disp := display.NewStderr()
+	pattern := args[0]
+
+	// Load configuration
+	cfg, err := config.LoadConfig()
+	if err != nil {
+		return eris.Wrap(err, "failed to load configuration")
+	}
+
+	var projects []*models.Project
+	if grepProjectName != "" {
+		// Get current working directory
+		cwd, err := os.Getwd()
+		if err != nil {
+			return eris.Wrap(err, "failed to get current working directory")
+		}
+
+		proj, err := project.ResolveProject(cfg.WorkspaceDir, grepProjectName, cwd)
+		if err != nil {
+			return eris.Wrap(err, "failed to resolve project")
+		}
+		projects = append(projects, proj)
+	} else {
+		projects, err = state.DiscoverProjects(cfg.WorkspaceDir)
+		if err != nil {
+			return eris.Wrap(err, "failed to discover projects")
+		}
+	}
+
+	if len(projects) == 0 {
+		return eris.New("no projects found in workspace")
+	}
+
+	useRipgrep := isRipgrepAvailable()
+	matched := false
+	failed := 0
+
+	for _, proj := range projects {
+		worktrees, err := state.DiscoverWorktrees(proj)
+		if err != nil {
+			disp.Warningf("failed to list worktrees for %s: %v", proj.Name, err)
+			failed++
+			continue
+		}
+
+		for _, wt := range worktrees {
+			// The bare repository is listed as a worktree; there is nothing to search there
+			if isBareRepoEntry(proj, wt) {
+				continue
+			}
+
+			prefix := fmt.Sprintf("%s:%s:", proj.Name, worktreeLabel(wt))
+			found, err := searchWorktree(wt.Path, pattern, prefix, useRipgrep, grepIgnoreCase, os.Stdout)
+			if err != nil {
+				disp.Warningf("search failed in %s: %v", wt.Path, err)
+				failed++
+			}
+			matched = matched || found
+		}
+	}
+
+	if !matched {
+		// Don't let failed searches look like a clean "no matches"
+		if failed > 0 {
+			return eris.Errorf("no matches found, and %d search(es) failed", failed)
+		}
+		// Like grep and rg, exit with status 1 when nothing matched so scripts can branch on it
+		return exitWithStatus(cmd, 1)
+	}
+
+	return nil
+}
+
+// isRipgrepAvailable checks if ripgrep is installed
+func isRipgrepAvailable() bool {
+	_, err := exec.LookPath("rg")
+	return err == nil
+}
+
+// buildSearchArgs returns the command and arguments used to search a worktree
+func buildSearchArgs(pattern string, useRipgrep, ignoreCase bool) (string, []string) {
+	if useRipgrep {
+		args := []string{"--line-number", "--no-heading", "--color=never"}
+		if ignoreCase {
+			args = append(args, "--ignore-case")
+		}
+		// Pass the path explicitly so rg never decides to search stdin instead
+		return "rg", append(args, "--regexp", pattern, ".")
+	}
+
+	args := []string{"grep", "--line-number", "--no-color", "-I"}
+	if ignoreCase {
+		args = append(args, "--ignore-case")
+	}
+	return "git", append(args, "-e", pattern)
+}
+
+// searchWorktree searches a worktree and writes each result to out prefixed with prefix
+// Returns whether anything matched
+func searchWorktree(
+	worktreePath, pattern, prefix string,
+	useRipgrep, ignoreCase bool,
+	out io.Writer,
+) (bool, error) {
+	name, args := buildSearchArgs(pattern, useRipgrep, ignoreCase)
+	cmd := exec.Command(name, args...)
+	cmd.Dir = worktreePath
+	cmd.Stderr = os.Stderr
+
+	stdout, err := cmd.StdoutPipe()
+	if err != nil {
+		return false, eris.Wrap(err, "failed to create stdout pipe")
+	}
+
+	if err := cmd.Start(); err != nil {
+		return false, eris.Wrapf(err, "failed to start %s", name)
+	}
+
+	if err := prefixLines(stdout, out, prefix); err != nil {
+		_ = cmd.Wait()
+		return false, err
+	}
+
+	if err := cmd.Wait(); err != nil {
+		// Both rg and git grep exit with 1 when nothing matched
+		if exitErr, ok := err.(*exec.ExitError); ok && exitErr.ExitCode() == 1 {
+			return false, nil
+		}
+		return false, eris.Wrapf(err, "%s failed", name)
+	}
+
+	return true, nil
+}
+
+// prefixLines copies each line from r to w, prefixed with prefix
+func prefixLines(r io.Reader, w io.Writer, prefix string) error {
+	scanner := bufio.NewScanner(r)
+	scanner.Buffer(make([]byte, 0, 64*1024), 1024*1024)
+	for scanner.Scan() {
+		// rg prints paths relative to the searched "." as "./path"; match git grep's format
+		line := strings.TrimPrefix(scanner.Text(), "./")
+		if _, err := fmt.Fprintf(w, "%s%s\n", prefix, line); err != nil {
+			return eris.Wrap(err, "failed to write search result")
+		}
+	}
+	if err := scanner.Err(); err != nil {
+		return eris.Wrap(err, "failed to read search results")
+	}
+	return nil
+}
@@ -0,0 +1,141 @@
+package cmd
+
+import (
+	"bytes"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"slices"
+	"strings"
+	"testing"
+)
+
+func TestPrefixLines(t *testing.T) {
+	input := "main.go:10:func main() {\n./cmd/root.go:3:// TODO\n"
+	var out bytes.Buffer
+
+	if err := prefixLines(strings.NewReader(input), &out, "github.com/user/repo:main:"); err != nil {
+		t.Fatalf("prefixLines() returned error: %v", err)
+	}
+
+	want := "github.com/user/repo:main:main.go:10:func main() {\n" +
+		"github.com/user/repo:main:cmd/root.go:3:// TODO\n"
+	if out.String() != want {
+		t.Errorf("prefixLines() = %q, want %q", out.String(), want)
+	}
+}
+
+func TestBuildSearchArgs(t *testing.T) {
+	tests := []struct {
+		name       string
+		useRipgrep bool
+		ignoreCase bool
+		wantName   string
+		wantArgs   []string
+	}{
+		{
+			name:       "ripgrep",
+			useRipgrep: true,
+			wantName:   "rg",
+			wantArgs:   []string{"--line-number", "--no-heading", "--color=never", "--regexp", "-foo", "."},
+		},
+		{
+			name:       "ripgrep ignore case",
+			useRipgrep: true,
+			ignoreCase: true,
+			wantName:   "rg",
+			wantArgs: []string{
+				"--line-number", "--no-heading", "--color=never", "--ignore-case", "--regexp", "-foo", ".",
+			},
+		},
+		{
+			name:     "git grep fallback",
+			wantName: "git",
+			wantArgs: []string{"grep", "--line-number", "--no-color", "-I", "-e", "-foo"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			gotName, gotArgs := buildSearchArgs("-foo", tt.useRipgrep, tt.ignoreCase)
+			if gotName != tt.wantName {
+				t.Errorf("buildSearchArgs() name = %q, want %q", gotName, tt.wantName)
+			}
+			if !slices.Equal(gotArgs, tt.wantArgs) {
+				t.Errorf("buildSearchArgs() args = %v, want %v", gotArgs, tt.wantArgs)
+			}
+		})
+	}
+}
+
+func TestSearchWorktree(t *testing.T) {
+	if _, err := exec.LookPath("git"); err != nil {
+		t.Skip("git not available")
+	}
+
+	dir := t.TempDir()
+	if output, err := exec.Command("git", "init", "-q", dir).CombinedOutput(); err != nil {
+		t.Fatalf("git init failed: %v\n%s", err, output)
+	}
+	if err := os.WriteFile(filepath.Join(dir, "main.go"), []byte("// TODO: fix\n"), 0o644); err != nil {
+		t.Fatalf("failed to write file: %v", err)
+	}
+	if output, err := exec.Command("git", "-C", dir, "add", "main.go").CombinedOutput(); err != nil {
+		t.Fatalf("git add failed: %v\n%s", err, output)
+	}
+
+	tests := []struct {
+		name        string
+		pattern     string
+		ignoreCase  bool
+		wantMatched bool
+		wantOutput  string
+	}{
+		{
+			name:        "match",
+			pattern:     "TODO",
+			wantMatched: true,
+			wantOutput:  "repo:main:main.go:1:// TODO: fix\n",
+		},
+		{
+			name:        "case-insensitive match",
+			pattern:     "todo",
+			ignoreCase:  true,
+			wantMatched: true,
+			wantOutput:  "repo:main:main.go:1:// TODO: fix\n",
+		},
+		{
+			name:    "case-sensitive miss",
+			pattern: "todo",
+		},
+		{
+			name:    "no match",
+			pattern: "FIXME",
+		},
+	}
+
+	for _, tool := range []string{"git", "rg"} {
+		useRipgrep := tool == "rg"
+		t.Run(tool, func(t *testing.T) {
+			if _, err := exec.LookPath(tool); err != nil {
+				t.Skipf("%s not available", tool)
+			}
+
+			for _, tt := range tests {
+				t.Run(tt.name, func(t *testing.T) {
+					var out bytes.Buffer
+					matched, err := searchWorktree(dir, tt.pattern, "repo:main:", useRipgrep, tt.ignoreCase, &out)
+					if err != nil {
+						t.Fatalf("searchWorktree() error = %v", err)
+					}
+					if matched != tt.wantMatched {
+						t.Errorf("searchWorktree() matched = %v, want %v", matched, tt.wantMatched)
+					}
+					if out.String() != tt.wantOutput {
+						t.Errorf("searchWorktree() output = %q, want %q", out.String(), tt.wantOutput)
+					}
+				})
+			}
+		})
+	}
+}
@@ -34,7 +34,7 @@ var (
 
 var listCmd = &cobra.Command{
 	Use:     "list",
-	Aliases: []string{"ls", "status"},
+	Aliases: []string{"ls"},
 	Short:   "List projects, worktrees, sessions, or pull requests",
 	Long: `Display all projects, worktrees, sessions, or pull requests.
 
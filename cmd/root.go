@@ -1,6 +1,7 @@
 package cmd
 
 import (
+	"errors"
 	"fmt"
 	"os"
 
@@ -33,11 +34,33 @@ Shell Completion:
 // This is called by main.main(). It only needs to happen once to the rootCmd.
 func Execute() {
 	if err := rootCmd.Execute(); err != nil {
+		var exitErr *exitStatusError
+		if errors.As(err, &exitErr) {
+			os.Exit(exitErr.code)
+		}
 		fmt.Fprintf(os.Stderr, "%+v\n", eris.ToString(err, true))
 		os.Exit(1)
 	}
 }
 
+// exitStatusError ends a command with a non-zero exit status without printing an error
+// It is used when the outcome (e.g. conflicts, no matches) was already reported to the user
+type exitStatusError struct {
+	code int
+}
+
+func (e *exitStatusError) Error() string {
+	return fmt.Sprintf("exit status %d", e.code)
+}
+
+// exitWithStatus returns an error that makes sesh exit with code, and silences
+// cobra's error and usage output for cmd
+func exitWithStatus(cmd *cobra.Command, code int) error {
+	cmd.SilenceErrors = true
+	cmd.SilenceUsage = true
+	return &exitStatusError{code: code}
+}
+
 func init() {
 	// Global flags can be defined here
 }
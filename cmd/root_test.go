@@ -0,0 +1,25 @@
+package cmd
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/spf13/cobra"
+)
+
+func TestExitWithStatus(t *testing.T) {
+	cmd := &cobra.Command{Use: "test"}
+
+	err := exitWithStatus(cmd, 1)
+
+	var exitErr *exitStatusError
+	if !errors.As(err, &exitErr) {
+		t.Fatalf("exitWithStatus() = %v, want *exitStatusError", err)
+	}
+	if exitErr.code != 1 {
+		t.Errorf("code = %d, want 1", exitErr.code)
+	}
+	if !cmd.SilenceErrors || !cmd.SilenceUsage {
+		t.Error("exitWithStatus() did not silence cobra error and usage output")
+	}
+}
@@ -0,0 +1,53 @@
+package cmd
+
+import (
+	"github.com/benoctopus/sesh/internal/config"
+	"github.com/benoctopus/sesh/internal/display"
+	"github.com/benoctopus/sesh/internal/session"
+	"github.com/rotisserie/eris"
+	"github.com/spf13/cobra"
+)
+
+var sendCmd = &cobra.Command{
+	Use:   "send <session> <command>",
+	Short: "Send a command to a session",
+	Long: `Send text to a running session and press Enter, running it as a command.
+
+This works with any terminal session backend (tmux, zellij), giving scripts
+a uniform way to drive sessions.
+
+Examples:
+  sesh send myproject-main "make test"         # Run tests in a session
+  sesh send myproject-feature-foo "git pull"   # Pull in another session`,
+	Args: cobra.ExactArgs(2),
+	RunE: runSend,
+}
+
+func init() {
+	rootCmd.AddCommand(sendCmd)
+}
+
+func runSend(cmd *cobra.Command, args []string) error {
+	disp := display.NewStderr()
+	sessionName := args[0]
+	text := args[1]
+
+	// Load configuration
+	cfg, err := config.LoadConfig()
+	if err != nil {
+		return eris.Wrap(err, "failed to load configuration")
+	}
+
+	// Initialize session manager
+	sessionMgr, err := session.NewSessionManager(cfg.SessionBackend)
+	if err != nil {
+		return eris.Wrap(err, "failed to initialize session manager")
+	}
+
+	if err := sessionMgr.Send(sessionName, text); err != nil {
+		return eris.Wrapf(err, "failed to send command to session %s", sessionName)
+	}
+
+	disp.Successf("Sent to %s: %s", disp.Bold(sessionName), disp.Faint(text))
+	return nil
+}
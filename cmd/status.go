@@ -4,11 +4,13 @@ import (
 	"fmt"
 	"os"
 	"os/exec"
+	"path/filepath"
 	"strings"
 
 	"github.com/benoctopus/sesh/internal/config"
 	"github.com/benoctopus/sesh/internal/display"
 	"github.com/benoctopus/sesh/internal/git"
+	"github.com/benoctopus/sesh/internal/models"
 	"github.com/benoctopus/sesh/internal/project"
 	"github.com/benoctopus/sesh/internal/session"
 	"github.com/benoctopus/sesh/internal/state"
@@ -29,6 +31,10 @@ Shows details about:
 - Git status summary
 - Other available sessions for this project
 
+When run from a plain shell inside a worktree (outside of any session),
+the worktree is resolved from the current directory and the command to
+switch into its session is suggested.
+
 Examples:
   sesh status`,
 	RunE: runStatus,
@@ -85,21 +91,32 @@ func runStatus(cmd *cobra.Command, args []string) error {
 	disp.Printf("Remote: %s\n", proj.RemoteURL)
 
 	// Get current branch
+	currentWorktreePath := ""
 	gitRoot, err := project.FindGitRoot(cwd)
 	if err == nil {
-		branch, err := git.GetCurrentBranch(gitRoot)
-		if err == nil {
-			disp.Printf("Branch: %s\n", branch)
+		// Resolve the worktree from the current path so this works from a plain shell
+		// (no session) anywhere inside a worktree
+		worktree, err := findWorktreeByPath(proj, gitRoot)
+		if err != nil {
+			// Fall back to looking the worktree up by the checked out branch
+			if branch, err := git.GetCurrentBranch(gitRoot); err == nil {
+				worktree, _ = state.GetWorktree(proj, branch)
+			}
 		}
 
-		// Get worktree info from filesystem state
-		worktree, err := state.GetWorktree(proj, branch)
-		if err == nil {
+		if worktree != nil {
+			currentWorktreePath = resolvePath(worktree.Path)
+			disp.Printf("Branch: %s\n", worktreeLabel(worktree))
 			disp.Printf("Worktree: %s\n", worktree.Path)
 			disp.Printf("Last Used: %s\n", formatTimeAgo(worktree.LastUsed))
+		} else if branch, err := git.GetCurrentBranch(gitRoot); err == nil {
+			disp.Printf("Branch: %s\n", branch)
+		}
 
+		// Sessions are named after branches, so detached worktrees have none
+		if worktree != nil && worktree.Branch != "" {
 			// Generate session name
-			sessionName := workspace.GenerateSessionName(proj.Name, branch)
+			sessionName := workspace.GenerateSessionName(proj.Name, worktree.Branch)
 			disp.Printf("Session: %s\n", sessionName)
 
 			// Check if session is running
@@ -114,6 +131,12 @@ func runStatus(cmd *cobra.Command, args []string) error {
 
 			if currentSessionName != "" && currentSessionName == sessionName {
 				disp.Printf("(You are currently in this session)\n")
+			} else if err == nil {
+				disp.Printf(
+					"\n%s %s\n",
+					disp.InfoText("→"),
+					statusNextStep(worktree.Branch, exists),
+				)
 			}
 		}
 
@@ -135,11 +158,8 @@ func runStatus(cmd *cobra.Command, args []string) error {
 		disp.Println("\nOther Sessions:")
 		for _, wt := range worktrees {
 			// Skip current worktree
-			if gitRoot != "" {
-				currentBranch, err := git.GetCurrentBranch(gitRoot)
-				if err == nil && wt.Branch == currentBranch {
-					continue
-				}
+			if currentWorktreePath != "" && resolvePath(wt.Path) == currentWorktreePath {
+				continue
 			}
 
 			// Generate session name
@@ -167,6 +187,49 @@ func runStatus(cmd *cobra.Command, args []string) error {
 	return nil
 }
 
+// findWorktreeByPath finds the worktree of a project located at the given path
+// Paths are compared after resolving symlinks so that e.g. /tmp and /private/tmp match
+func findWorktreeByPath(proj *models.Project, path string) (*models.Worktree, error) {
+	worktrees, err := state.DiscoverWorktrees(proj)
+	if err != nil {
+		return nil, err
+	}
+
+	target := resolvePath(path)
+	for _, wt := range worktrees {
+		if resolvePath(wt.Path) == target {
+			return wt, nil
+		}
+	}
+
+	return nil, eris.Errorf("worktree not found at path: %s", path)
+}
+
+// resolvePath returns the symlink-free form of a path, or the cleaned path if it can't be resolved
+func resolvePath(path string) string {
+	resolved, err := filepath.EvalSymlinks(path)
+	if err != nil {
+		return filepath.Clean(path)
+	}
+	return resolved
+}
+
+// worktreeLabel returns the branch of a worktree, or a description of it if detached
+func worktreeLabel(wt *models.Worktree) string {
+	if wt.Branch == "" {
+		return fmt.Sprintf("detached HEAD at %s", filepath.Base(wt.Path))
+	}
+	return wt.Branch
+}
+
+// statusNextStep returns the suggested command for moving into the session of a worktree
+func statusNextStep(branch string, sessionExists bool) string {
+	if sessionExists {
+		return fmt.Sprintf("Run 'sesh switch %s' to attach to this session", branch)
+	}
+	return fmt.Sprintf("Run 'sesh switch %s' to start a session for this worktree", branch)
+}
+
 // getGitStatusSummary returns a summary of the git status
 func getGitStatusSummary(repoPath string) (string, error) {
 	cmd := exec.Command("git", "-C", repoPath, "status", "--porcelain")
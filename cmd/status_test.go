@@ -1,7 +1,12 @@
 package cmd
 
 import (
+	"os"
+	"os/exec"
+	"path/filepath"
 	"testing"
+
+	"github.com/benoctopus/sesh/internal/models"
 )
 
 func TestGetGitStatusSummary(t *testing.T) {
@@ -14,3 +19,138 @@ func TestGetGitStatusSummary(t *testing.T) {
 		t.Skip("Requires integration test setup")
 	})
 }
+
+func TestStatusNextStep(t *testing.T) {
+	tests := []struct {
+		name          string
+		branch        string
+		sessionExists bool
+		want          string
+	}{
+		{
+			name:          "session running",
+			branch:        "feature-foo",
+			sessionExists: true,
+			want:          "Run 'sesh switch feature-foo' to attach to this session",
+		},
+		{
+			name:          "session not running",
+			branch:        "main",
+			sessionExists: false,
+			want:          "Run 'sesh switch main' to start a session for this worktree",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := statusNextStep(tt.branch, tt.sessionExists)
+			if got != tt.want {
+				t.Errorf("statusNextStep(%q, %v) = %q, want %q", tt.branch, tt.sessionExists, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestWorktreeLabel(t *testing.T) {
+	tests := []struct {
+		name string
+		wt   *models.Worktree
+		want string
+	}{
+		{
+			name: "branch",
+			wt:   &models.Worktree{Branch: "feature-foo", Path: "/ws/repo/feature-foo"},
+			want: "feature-foo",
+		},
+		{
+			name: "detached",
+			wt:   &models.Worktree{Path: "/ws/repo/v1.2.0"},
+			want: "detached HEAD at v1.2.0",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := worktreeLabel(tt.wt); got != tt.want {
+				t.Errorf("worktreeLabel() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestStatusCommandDispatch(t *testing.T) {
+	cmd, _, err := rootCmd.Find([]string{"status"})
+	if err != nil {
+		t.Fatalf("rootCmd.Find(status) error = %v", err)
+	}
+	if cmd != statusCmd {
+		t.Errorf("rootCmd.Find(status) = %q, want %q", cmd.Name(), statusCmd.Name())
+	}
+}
+
+func TestFindWorktreeByPath(t *testing.T) {
+	if _, err := exec.LookPath("git"); err != nil {
+		t.Skip("git not available")
+	}
+
+	dir := t.TempDir()
+	srcPath := filepath.Join(dir, "src")
+	barePath := filepath.Join(dir, "repo.git")
+	worktreePath := filepath.Join(dir, "repo", "feature")
+
+	runGit := func(args ...string) {
+		t.Helper()
+		cmd := exec.Command("git", args...)
+		cmd.Env = append(os.Environ(),
+			"GIT_AUTHOR_NAME=test", "GIT_AUTHOR_EMAIL=test@example.com",
+			"GIT_COMMITTER_NAME=test", "GIT_COMMITTER_EMAIL=test@example.com",
+		)
+		if output, err := cmd.CombinedOutput(); err != nil {
+			t.Fatalf("git %v failed: %v\n%s", args, err, output)
+		}
+	}
+	runGit("init", "-q", srcPath)
+	runGit("-C", srcPath, "commit", "-q", "--allow-empty", "-m", "initial")
+	runGit("clone", "-q", "--bare", srcPath, barePath)
+	runGit("-C", barePath, "worktree", "add", "-q", "-b", "feature", worktreePath)
+	detachedPath := filepath.Join(dir, "repo", "detached")
+	runGit("-C", barePath, "worktree", "add", "-q", "--detach", detachedPath)
+
+	linkPath := filepath.Join(dir, "link")
+	if err := os.Symlink(worktreePath, linkPath); err != nil {
+		t.Fatalf("failed to create symlink: %v", err)
+	}
+
+	proj := &models.Project{Name: "repo", LocalPath: barePath}
+
+	tests := []struct {
+		name       string
+		path       string
+		wantBranch string
+		wantErr    bool
+	}{
+		{name: "exact path", path: worktreePath, wantBranch: "feature"},
+		{name: "trailing slash", path: worktreePath + "/", wantBranch: "feature"},
+		{name: "through symlink", path: linkPath, wantBranch: "feature"},
+		{name: "detached worktree", path: detachedPath, wantBranch: ""},
+		{name: "not a worktree", path: dir, wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := findWorktreeByPath(proj, tt.path)
+			if tt.wantErr {
+				if err == nil {
+					t.Errorf("findWorktreeByPath(%q) = %+v, want error", tt.path, got)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("findWorktreeByPath(%q) error = %v", tt.path, err)
+			}
+			if got.Branch != tt.wantBranch {
+				t.Errorf("findWorktreeByPath(%q).Branch = %q, want %q", tt.path, got.Branch, tt.wantBranch)
+			}
+		})
+	}
+}
@@ -1,16 +1,20 @@
 package cmd
 
 import (
+	"bufio"
 	"fmt"
 	"io"
 	"os"
+	"os/user"
 	"strings"
+	"time"
 
 	"github.com/benoctopus/sesh/internal/config"
 	"github.com/benoctopus/sesh/internal/db"
 	"github.com/benoctopus/sesh/internal/display"
 	"github.com/benoctopus/sesh/internal/fuzzy"
 	"github.com/benoctopus/sesh/internal/git"
+	"github.com/benoctopus/sesh/internal/models"
 	"github.com/benoctopus/sesh/internal/pr"
 	"github.com/benoctopus/sesh/internal/project"
 	"github.com/benoctopus/sesh/internal/session"
@@ -26,6 +30,9 @@ var (
 	switchStartupCommand string
 	switchPR             bool
 	switchDetach         bool
+	switchNew            bool
+	switchTicket         string
+	switchDescription    string
 )
 
 var switchCmd = &cobra.Command{
@@ -41,6 +48,10 @@ or can be specified explicitly with the --project flag.
 
 If the branch doesn't exist locally or remotely, a new branch will be created automatically.
 
+Use --new without a branch name to generate one from the branch_template config
+(by default <user>/<date>[-<ticket>][-<description>]) and create it from the
+default branch. In interactive mode you are prompted for an optional description.
+
 If a git URL is provided for the --project flag and the repository has not been cloned yet,
 it will be automatically cloned before switching to the branch.
 
@@ -53,7 +64,9 @@ Examples:
   sesh switch -p git@github.com:user/repo.git main           # Auto-clone and switch
   sesh switch -p https://github.com/user/repo.git feature    # Auto-clone HTTPS URL
   sesh switch -c "direnv allow" feature-baz                  # Run startup command
-  sesh switch -d feature-test                                # Create session without attaching`,
+  sesh switch -d feature-test                                # Create session without attaching
+  sesh switch --new                                          # Generate a branch name, e.g. alice/2025-01-31
+  sesh switch --new --ticket ABC-123 --desc "fix login"      # alice/2025-01-31-ABC-123-fix-login`,
 	RunE: runSwitch,
 }
 
@@ -67,6 +80,12 @@ func init() {
 		BoolVar(&switchPR, "pr", false, "Select from open pull requests")
 	switchCmd.Flags().
 		BoolVarP(&switchDetach, "detach", "d", false, "Create session without attaching to it")
+	switchCmd.Flags().
+		BoolVarP(&switchNew, "new", "n", false, "Create a new branch with a generated name from the default branch")
+	switchCmd.Flags().
+		StringVar(&switchTicket, "ticket", "", "Ticket identifier to include in the generated branch name (with --new)")
+	switchCmd.Flags().
+		StringVar(&switchDescription, "desc", "", "Short description to include in the generated branch name (with --new)")
 }
 
 func runSwitch(cmd *cobra.Command, args []string) error {
@@ -115,8 +134,31 @@ func runSwitch(cmd *cobra.Command, args []string) error {
 
 	var branch string
 
-	// Handle PR selection if --pr flag is set
-	if switchPR {
+	// Start point for branches that don't exist locally or remotely
+	newBranchStartPoint := "HEAD"
+
+	// Handle branch name generation if --new flag is set
+	if switchNew {
+		if len(args) > 0 {
+			return eris.New("cannot specify branch name with --new flag")
+		}
+		if switchPR {
+			return eris.New("cannot use --new with --pr flag")
+		}
+
+		branch, newBranchStartPoint, err = generateNewBranch(cfg, proj, disp)
+		if err != nil {
+			return err
+		}
+
+		disp.Printf(
+			"%s Generated branch name: %s (from %s)\n",
+			disp.InfoText("→"),
+			disp.Bold(branch),
+			newBranchStartPoint,
+		)
+	} else if switchPR {
+		// Handle PR selection if --pr flag is set
 		disp := display.NewStderr()
 
 		if len(args) > 0 {
@@ -298,19 +340,7 @@ func runSwitch(cmd *cobra.Command, args []string) error {
 		}
 
 		// Execute startup command if configured
-		startupCmd := getStartupCommand(cfg, existingWorktree.Path)
-		if startupCmd != "" && sessionMgr.Name() == "tmux" {
-			disp.Printf(
-				"%s Running startup command: %s\n",
-				disp.InfoText("⚙"),
-				disp.Faint(startupCmd),
-			)
-			if tmuxMgr, ok := sessionMgr.(*session.TmuxManager); ok {
-				if err := tmuxMgr.SendKeys(sessionName, startupCmd); err != nil {
-					fmt.Fprintf(os.Stderr, "Warning: failed to run startup command: %v\n", err)
-				}
-			}
-		}
+		sendStartupCommand(disp, sessionMgr, sessionName, getStartupCommand(cfg, existingWorktree.Path))
 
 		// Record session history before attaching
 		recordSessionHistory(sessionName, proj.Name, branch)
@@ -361,8 +391,9 @@ func runSwitch(cmd *cobra.Command, args []string) error {
 			}
 		} else {
 			// Branch doesn't exist anywhere, create new branch and worktree from HEAD
+			// (or from the default branch when the name was generated with --new)
 			disp.Printf("%s Creating new branch and worktree: %s\n", disp.SuccessText("✨"), disp.Bold(branch))
-			if err := git.CreateWorktreeNewBranch(proj.LocalPath, branch, worktreePath, "HEAD"); err != nil {
+			if err := git.CreateWorktreeNewBranch(proj.LocalPath, branch, worktreePath, newBranchStartPoint); err != nil {
 				return eris.Wrap(err, "failed to create worktree with new branch")
 			}
 		}
@@ -385,15 +416,7 @@ func runSwitch(cmd *cobra.Command, args []string) error {
 	disp.Printf("  %s %s\n", disp.Faint("Session:"), sessionName)
 
 	// Execute startup command if configured
-	startupCmd := getStartupCommand(cfg, worktreePath)
-	if startupCmd != "" && sessionMgr.Name() == "tmux" {
-		disp.Printf("%s Running startup command: %s\n", disp.InfoText("⚙"), disp.Faint(startupCmd))
-		if tmuxMgr, ok := sessionMgr.(*session.TmuxManager); ok {
-			if err := tmuxMgr.SendKeys(sessionName, startupCmd); err != nil {
-				fmt.Fprintf(os.Stderr, "Warning: failed to run startup command: %v\n", err)
-			}
-		}
-	}
+	sendStartupCommand(disp, sessionMgr, sessionName, getStartupCommand(cfg, worktreePath))
 
 	// Record session history before attaching
 	recordSessionHistory(sessionName, proj.Name, branch)
@@ -408,6 +431,74 @@ func runSwitch(cmd *cobra.Command, args []string) error {
 	return sessionMgr.Attach(sessionName)
 }
 
+// generateNewBranch generates an unused branch name from the configured branch template
+// and returns it together with the default branch ref it should be created from
+func generateNewBranch(cfg *config.Config, proj *models.Project, disp display.Printer) (string, string, error) {
+	description := switchDescription
+	if description == "" && switchTicket == "" && tty.IsInteractive() {
+		disp.Print("Short description (optional): ")
+		reader := bufio.NewReader(os.Stdin)
+		response, err := reader.ReadString('\n')
+		if err != nil && response == "" {
+			return "", "", eris.Wrap(err, "failed to read description")
+		}
+		description = strings.TrimSpace(response)
+	}
+
+	data := workspace.NewBranchNameData(currentUserName(), time.Now(), switchTicket, description)
+	baseName, err := workspace.GenerateBranchName(cfg.BranchTemplate, data)
+	if err != nil {
+		return "", "", eris.Wrap(err, "failed to generate branch name")
+	}
+
+	// Catch names git rejects (e.g. "..", ".lock") before creating anything
+	if err := git.ValidateBranchName(baseName); err != nil {
+		return "", "", eris.Wrap(err, "generated branch name is invalid, adjust --desc, --ticket or branch_template")
+	}
+
+	// Refresh remote branches so both the uniqueness check and the start point are current
+	if err := git.Fetch(proj.LocalPath); err != nil {
+		disp.Warningf("git fetch failed, using local branches: %s", eris.ToString(err, false))
+	}
+
+	// Append a counter if the name is taken, e.g. for several experiments on the same day
+	branch := baseName
+	for i := 2; ; i++ {
+		existsLocally, _, err := git.DoesBranchExist(proj.LocalPath, branch)
+		if err != nil {
+			return "", "", eris.Wrap(err, "failed to check branch existence")
+		}
+		existsRemotely, err := git.DoesBranchExistRemotely(proj.LocalPath, branch)
+		if err != nil {
+			return "", "", eris.Wrap(err, "failed to check remote branch existence")
+		}
+		if !existsLocally && !existsRemotely {
+			break
+		}
+		branch = fmt.Sprintf("%s-%d", baseName, i)
+	}
+
+	defaultBranch, err := git.GetDefaultBranch(proj.LocalPath)
+	if err != nil {
+		return "", "", eris.Wrap(err, "failed to get default branch")
+	}
+
+	startPoint := defaultBranch
+	if existsRemotely, _ := git.DoesBranchExistRemotely(proj.LocalPath, defaultBranch); existsRemotely {
+		startPoint = "origin/" + defaultBranch
+	}
+
+	return branch, startPoint, nil
+}
+
+// currentUserName returns the name of the current user for branch name templates
+func currentUserName() string {
+	if u, err := user.Current(); err == nil && u.Username != "" {
+		return u.Username
+	}
+	return os.Getenv("USER")
+}
+
 // recordSessionHistory records the session access in the database for session history (pop command)
 // This is a best-effort operation - errors are logged but don't fail the command
 func recordSessionHistory(sessionName, projectName, branch string) {
@@ -455,6 +546,26 @@ func getStartupCommand(cfg *config.Config, worktreePath string) string {
 	return cfg.StartupCommand
 }
 
+// sendStartupCommand sends the startup command to a newly created session
+// Backends that can't send text (none, editors) are skipped silently
+// This is a best-effort operation - failures are reported as warnings
+func sendStartupCommand(disp display.Printer, sessionMgr session.SessionManager, sessionName, startupCmd string) {
+	if startupCmd == "" {
+		return
+	}
+
+	err := sessionMgr.Send(sessionName, startupCmd)
+	switch {
+	case eris.Is(err, session.ErrSendUnsupported):
+		// Backends without a terminal (none, editors) have nowhere to run the command
+		return
+	case err != nil:
+		disp.Warningf("failed to run startup command: %v", err)
+	default:
+		disp.Printf("%s Ran startup command: %s\n", disp.InfoText("⚙"), disp.Faint(startupCmd))
+	}
+}
+
 // cloneRepository clones a repository into the workspace
 // This is used when auto-cloning a repository specified by git URL
 func cloneRepository(cfg *config.Config, remoteURL, projectName string) error {
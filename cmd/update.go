@@ -0,0 +1,218 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+
+	"github.com/benoctopus/sesh/internal/config"
+	"github.com/benoctopus/sesh/internal/display"
+	"github.com/benoctopus/sesh/internal/git"
+	"github.com/benoctopus/sesh/internal/models"
+	"github.com/benoctopus/sesh/internal/project"
+	"github.com/benoctopus/sesh/internal/state"
+	"github.com/rotisserie/eris"
+	"github.com/spf13/cobra"
+)
+
+var (
+	updateProjectName string
+	updateMerge       bool
+	updateOutput      string
+)
+
+var updateCmd = &cobra.Command{
+	Use:     "update [-p project] [branch]",
+	Aliases: []string{"up"},
+	Short:   "Update a worktree with the latest default branch",
+	Long: `Fetch the latest changes and rebase a worktree's branch onto the remote default branch.
+Use --merge to merge the default branch instead of rebasing.
+
+By default, the worktree for the current directory is updated. Pass a branch
+name to update another worktree of the project.
+
+If the update stops because of conflicts, a report is shown listing the
+conflicted files, which side "ours" and "theirs" refer to, the state the
+worktree was left in, and the commands to continue or abort.
+Use --output json to get the report in a machine-readable format.
+
+Examples:
+  sesh update                          # Rebase current worktree onto origin/<default>
+  sesh update feature-foo              # Update another worktree of this project
+  sesh update --merge                  # Merge instead of rebasing
+  sesh update --output json            # Print the result as JSON`,
+	Args: cobra.MaximumNArgs(1),
+	RunE: runUpdate,
+}
+
+func init() {
+	rootCmd.AddCommand(updateCmd)
+	updateCmd.Flags().
+		StringVarP(&updateProjectName, "project", "p", "", "Specify project explicitly")
+	updateCmd.Flags().
+		BoolVar(&updateMerge, "merge", false, "Merge the default branch instead of rebasing")
+	updateCmd.Flags().
+		StringVarP(&updateOutput, "output", "o", "text", "Output format (text, json)")
+}
+
+// updateResult is the machine-readable result of an update
+type updateResult struct {
+	Status       string              `json:"status"` // "updated" or "conflicted"
+	Operation    git.UpdateOperation `json:"operation"`
+	Branch       string              `json:"branch"`
+	Onto         string              `json:"onto"`
+	WorktreePath string              `json:"worktree_path"`
+	Conflict     *git.ConflictReport `json:"conflict,omitempty"`
+}
+
+func runUpdate(cmd *cobra.Command, args []string) error {
+	disp := display.NewStderr()
+
+	if updateOutput != "text" && updateOutput != "json" {
+		return eris.Errorf("invalid output format: %s (must be one of: text, json)", updateOutput)
+	}
+
+	// Load configuration
+	cfg, err := config.LoadConfig()
+	if err != nil {
+		return eris.Wrap(err, "failed to load configuration")
+	}
+
+	// Get current working directory
+	cwd, err := os.Getwd()
+	if err != nil {
+		return eris.Wrap(err, "failed to get current working directory")
+	}
+
+	// Resolve project from filesystem state
+	proj, err := project.ResolveProject(cfg.WorkspaceDir, updateProjectName, cwd)
+	if err != nil {
+		return eris.Wrap(err, "failed to resolve project")
+	}
+
+	worktree, err := resolveUpdateWorktree(proj, args, cwd)
+	if err != nil {
+		return err
+	}
+
+	// Refuse to start with local changes so a failed update can always be aborted cleanly
+	// Untracked files are fine, git refuses on its own if one would be overwritten
+	dirty, err := git.HasTrackedChanges(worktree.Path)
+	if err != nil {
+		return eris.Wrap(err, "failed to check worktree status")
+	}
+	if dirty {
+		return eris.Errorf(
+			"worktree %s has uncommitted changes; commit or stash them before updating",
+			worktree.Path,
+		)
+	}
+
+	disp.Printf("%s Fetching %s\n", disp.InfoText("⬇"), disp.Bold(proj.Name))
+	if err := git.Fetch(proj.LocalPath); err != nil {
+		return eris.Wrap(err, "failed to fetch repository")
+	}
+
+	defaultBranch, err := git.GetDefaultBranch(proj.LocalPath)
+	if err != nil {
+		return eris.Wrap(err, "failed to get default branch")
+	}
+	onto := "origin/" + defaultBranch
+
+	op := git.UpdateRebase
+	update := git.Rebase
+	if updateMerge {
+		op = git.UpdateMerge
+		update = git.Merge
+	}
+
+	disp.Printf(
+		"%s Updating %s (%s onto %s)\n",
+		disp.InfoText("→"),
+		disp.Bold(worktree.Branch),
+		op,
+		onto,
+	)
+	report, err := update(worktree.Path, worktree.Branch, onto)
+	if err != nil {
+		return eris.Wrapf(err, "failed to update %s", worktree.Branch)
+	}
+
+	result := updateResult{
+		Status:       "updated",
+		Operation:    op,
+		Branch:       worktree.Branch,
+		Onto:         onto,
+		WorktreePath: worktree.Path,
+		Conflict:     report,
+	}
+	if report != nil {
+		result.Status = "conflicted"
+	}
+
+	if updateOutput == "json" {
+		data, err := json.MarshalIndent(result, "", "  ")
+		if err != nil {
+			return eris.Wrap(err, "failed to marshal update result to JSON")
+		}
+		// JSON output is pipeable, so use stdout
+		fmt.Println(string(data))
+	} else if report != nil {
+		printConflictReport(disp, report)
+	} else {
+		disp.Successf("Updated %s with %s", disp.Bold(worktree.Branch), onto)
+	}
+
+	// Conflicts are an expected outcome that was already reported; just signal it
+	if report != nil {
+		return exitWithStatus(cmd, 1)
+	}
+
+	return nil
+}
+
+// resolveUpdateWorktree returns the worktree for the branch argument, or the worktree
+// containing the current directory if no branch was given
+func resolveUpdateWorktree(proj *models.Project, args []string, cwd string) (*models.Worktree, error) {
+	if len(args) > 0 {
+		worktree, err := state.GetWorktree(proj, args[0])
+		if err != nil {
+			return nil, eris.Wrapf(err, "no worktree for branch %s (use 'sesh switch %s' first)", args[0], args[0])
+		}
+		return worktree, nil
+	}
+
+	gitRoot, err := project.FindGitRoot(cwd)
+	if err != nil {
+		return nil, eris.Wrap(err, "branch argument required outside of a worktree")
+	}
+
+	worktree, err := findWorktreeByPath(proj, gitRoot)
+	if err != nil {
+		return nil, eris.Wrap(err, "current directory is not a sesh-managed worktree")
+	}
+
+	return worktree, nil
+}
+
+// printConflictReport prints a human readable conflict report
+func printConflictReport(disp display.Printer, report *git.ConflictReport) {
+	disp.Println()
+	disp.Errorf("%s stopped with conflicts", report.Operation)
+	disp.Printf("  %s %s\n", disp.Faint("State:"), report.State)
+	disp.Printf("  %s %s\n", disp.Faint("Worktree:"), report.WorktreePath)
+	disp.Printf("  %s %s\n", disp.Faint("Ours (--ours):"), report.Ours)
+	disp.Printf("  %s %s\n", disp.Faint("Theirs (--theirs):"), report.Theirs)
+
+	disp.Printf("\n%s\n", disp.Bold("Conflicted files:"))
+	for _, file := range report.Files {
+		disp.Printf("  %s %s %s\n", disp.ErrorText(file.Code), file.Path, disp.Faint("("+file.Status+")"))
+		disp.Printf("     %s %s\n", disp.Faint("→"), file.Hint)
+	}
+
+	disp.Printf("\n%s\n", disp.Bold("Next steps:"))
+	for _, step := range report.NextSteps {
+		disp.Printf("  %s\n", step)
+	}
+	disp.Println()
+}
@@ -4,6 +4,7 @@ import (
 	"os"
 	"path/filepath"
 	"runtime"
+	"text/template"
 
 	"github.com/rotisserie/eris"
 	"gopkg.in/yaml.v3"
@@ -15,6 +16,7 @@ type Config struct {
 	SessionBackend string `yaml:"session_backend"` // "tmux", "zellij", "screen", "auto", or editor backends like "code:open", "cursor:replace"
 	StartupCommand string `yaml:"startup_command"` // Command to run on session creation
 	FuzzyFinder    string `yaml:"fuzzy_finder"`    // "fzf", "peco", "auto"
+	BranchTemplate string `yaml:"branch_template"` // Template for generated branch names (sesh switch --new)
 }
 
 // configFile represents the YAML config file structure
@@ -24,11 +26,17 @@ type configFile struct {
 	SessionBackend string `yaml:"session_backend"`
 	StartupCommand string `yaml:"startup_command"`
 	FuzzyFinder    string `yaml:"fuzzy_finder"`
+	BranchTemplate string `yaml:"branch_template"`
 }
 
 const (
 	// CurrentConfigVersion is the current version of the config file format
 	CurrentConfigVersion = "1"
+
+	// DefaultBranchTemplate is the template used to generate branch names for `sesh switch --new`
+	// Available fields: .User, .Date, .Ticket, .Slug
+	// Example output: "alice/2025-01-31-ABC-123-fix-login"
+	DefaultBranchTemplate = "{{.User}}/{{.Date}}{{with .Ticket}}-{{.}}{{end}}{{with .Slug}}-{{.}}{{end}}"
 )
 
 // ProjectConfig holds project-specific configuration
@@ -125,6 +133,23 @@ func GetFuzzyFinder() (string, error) {
 	return "auto", nil
 }
 
+// GetBranchTemplate returns the branch name template with configuration hierarchy
+func GetBranchTemplate() (string, error) {
+	// 1. Environment variable (highest priority)
+	if envTemplate := os.Getenv("SESH_BRANCH_TEMPLATE"); envTemplate != "" {
+		return envTemplate, nil
+	}
+
+	// 2. Config file
+	config, err := loadConfigFile()
+	if err == nil && config.BranchTemplate != "" {
+		return config.BranchTemplate, nil
+	}
+
+	// 3. Default
+	return DefaultBranchTemplate, nil
+}
+
 // GetDBPath returns the full path to the SQLite database
 func GetDBPath() (string, error) {
 	configDir, err := GetConfigDir()
@@ -185,11 +210,17 @@ func LoadConfig() (*Config, error) {
 		return nil, eris.Wrap(err, "failed to get fuzzy finder")
 	}
 
+	branchTemplate, err := GetBranchTemplate()
+	if err != nil {
+		return nil, eris.Wrap(err, "failed to get branch template")
+	}
+
 	return &Config{
 		WorkspaceDir:   workspaceDir,
 		SessionBackend: sessionBackend,
 		StartupCommand: startupCommand,
 		FuzzyFinder:    fuzzyFinder,
+		BranchTemplate: branchTemplate,
 	}, nil
 }
 
@@ -314,6 +345,7 @@ func SaveConfig(config *Config) error {
 		SessionBackend: config.SessionBackend,
 		StartupCommand: config.StartupCommand,
 		FuzzyFinder:    config.FuzzyFinder,
+		BranchTemplate: config.BranchTemplate,
 	}
 
 	// Marshal to YAML
@@ -370,6 +402,13 @@ func ValidateConfig(config *configFile) error {
 		}
 	}
 
+	// Validate branch template (if provided, it should parse)
+	if config.BranchTemplate != "" {
+		if _, err := template.New("branch").Parse(config.BranchTemplate); err != nil {
+			return eris.Wrap(err, "invalid branch_template")
+		}
+	}
+
 	// Validate workspace directory (if provided, it should be expandable)
 	if config.WorkspaceDir != "" {
 		_, err := expandHome(config.WorkspaceDir)
@@ -430,6 +430,22 @@ func TestValidateConfig(t *testing.T) {
 			},
 			wantErr: true,
 		},
+		{
+			name: "valid branch template",
+			config: configFile{
+				Version:        "1",
+				BranchTemplate: "{{.User}}/{{.Slug}}",
+			},
+			wantErr: false,
+		},
+		{
+			name: "invalid branch template",
+			config: configFile{
+				Version:        "1",
+				BranchTemplate: "{{.User",
+			},
+			wantErr: true,
+		},
 		{
 			name: "valid empty config",
 			config: configFile{
@@ -0,0 +1,162 @@
+package doctor
+
+import (
+	"errors"
+	"fmt"
+	"io/fs"
+	"os"
+	"os/user"
+	"path/filepath"
+	"strconv"
+	"strings"
+
+	"github.com/benoctopus/sesh/internal/shell"
+)
+
+// PathCheck is the result of checking the ownership and permissions of a path
+type PathCheck struct {
+	Label    string
+	Path     string
+	Missing  bool     // Path does not exist (not a problem, sesh creates it on demand)
+	Problems []string // Human readable descriptions of what is wrong
+	Fixes    []string // Suggested shell commands to fix the problems
+}
+
+// OK reports whether no problems were found for the path
+func (c *PathCheck) OK() bool {
+	return len(c.Problems) == 0
+}
+
+// addProblem records a problem and its suggested fix, skipping duplicate fixes
+func (c *PathCheck) addProblem(problem, fix string) {
+	c.Problems = append(c.Problems, problem)
+	for _, existing := range c.Fixes {
+		if existing == fix {
+			return
+		}
+	}
+	c.Fixes = append(c.Fixes, fix)
+}
+
+// CheckPath checks that path is owned by and accessible to the current user
+// If recursive is true and path is a directory, its contents are also checked for
+// entries owned by other users (e.g. files created by git running under sudo)
+func CheckPath(label, path string, recursive bool) *PathCheck {
+	check := &PathCheck{Label: label, Path: path}
+
+	info, err := os.Stat(path)
+	if errors.Is(err, fs.ErrNotExist) {
+		check.Missing = true
+		return check
+	}
+	if err != nil {
+		check.addProblem(fmt.Sprintf("cannot be accessed: %v", err), chownFix(path, recursive))
+		return check
+	}
+
+	uid := os.Getuid()
+	if owner, ok := fileOwner(info); ok && owner != uid {
+		check.addProblem(
+			fmt.Sprintf("owned by %s instead of %s", userName(owner), userName(uid)),
+			chownFix(path, recursive),
+		)
+	}
+
+	perms := "u+rw"
+	if info.IsDir() {
+		perms = "u+rwx"
+	}
+	if err := checkAccess(path, info.IsDir()); err != nil {
+		check.addProblem(
+			fmt.Sprintf("not readable and writable by %s", userName(uid)),
+			fmt.Sprintf("chmod %s %s", perms, shell.Quote(path)),
+		)
+	}
+
+	if recursive && info.IsDir() && check.OK() {
+		if entry, problem := findProblemEntry(path, uid); entry != "" {
+			check.addProblem(fmt.Sprintf("contains %s which %s", entry, problem), chownFix(path, true))
+		}
+	}
+
+	return check
+}
+
+// maxWalkDepth limits how far below the checked path recursive checks look
+// Files left behind by git running under sudo sit near the top (refs, objects,
+// checked out files), so a shallow walk finds them without reading whole trees
+const maxWalkDepth = 4
+
+// skippedDirs are dependency and build directories that can hold huge numbers of
+// files and are managed by their own tools rather than by git
+var skippedDirs = map[string]bool{
+	"node_modules": true,
+	"target":       true,
+	".venv":        true,
+	"venv":         true,
+	"__pycache__":  true,
+	".next":        true,
+	".gradle":      true,
+	".terraform":   true,
+}
+
+// findProblemEntry walks root up to maxWalkDepth levels deep, skipping skippedDirs,
+// and returns the first entry that is owned by another user or can't be read along
+// with a description of the problem, or empty strings if nothing was found
+func findProblemEntry(root string, uid int) (string, string) {
+	var entry, problem string
+	rootDepth := pathDepth(root)
+
+	_ = filepath.WalkDir(root, func(path string, d fs.DirEntry, err error) error {
+		if err == nil {
+			var info fs.FileInfo
+			info, err = d.Info()
+			if err == nil {
+				if owner, ok := fileOwner(info); ok && owner != uid {
+					entry, problem = path, "is owned by another user"
+					return filepath.SkipAll
+				}
+			}
+		}
+		if err != nil {
+			// Entries can disappear while walking, e.g. during a build
+			if errors.Is(err, fs.ErrNotExist) {
+				return nil
+			}
+			entry, problem = path, "cannot be read"
+			return filepath.SkipAll
+		}
+
+		if d.IsDir() && path != root {
+			if skippedDirs[d.Name()] || pathDepth(path)-rootDepth >= maxWalkDepth {
+				return filepath.SkipDir
+			}
+		}
+		return nil
+	})
+
+	return entry, problem
+}
+
+// pathDepth returns the number of path elements in path
+func pathDepth(path string) int {
+	return strings.Count(filepath.Clean(path), string(filepath.Separator))
+}
+
+// userName returns the user name for a uid, falling back to the numeric id
+func userName(uid int) string {
+	u, err := user.LookupId(strconv.Itoa(uid))
+	if err != nil {
+		return strconv.Itoa(uid)
+	}
+	return u.Username
+}
+
+// chownFix returns the command that gives ownership of path back to the current user
+func chownFix(path string, recursive bool) string {
+	flag := ""
+	if recursive {
+		flag = "-R "
+	}
+	return fmt.Sprintf("sudo chown %s%s %s", flag, userName(os.Getuid()), shell.Quote(path))
+}
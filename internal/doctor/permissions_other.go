@@ -0,0 +1,15 @@
+//go:build !unix
+
+package doctor
+
+import "os"
+
+// fileOwner is not supported on this platform, so ownership checks are skipped
+func fileOwner(info os.FileInfo) (int, bool) {
+	return 0, false
+}
+
+// checkAccess is not supported on this platform, so access checks are skipped
+func checkAccess(path string, isDir bool) error {
+	return nil
+}
@@ -0,0 +1,157 @@
+package doctor
+
+import (
+	"os"
+	"path/filepath"
+	"runtime"
+	"strings"
+	"testing"
+)
+
+func TestCheckPath(t *testing.T) {
+	t.Run("missing path", func(t *testing.T) {
+		check := CheckPath("Config directory", filepath.Join(t.TempDir(), "missing"), false)
+		if !check.Missing {
+			t.Error("Missing = false, want true")
+		}
+		if !check.OK() {
+			t.Errorf("OK() = false, want true (problems: %v)", check.Problems)
+		}
+	})
+
+	t.Run("owned and writable directory", func(t *testing.T) {
+		dir := t.TempDir()
+		if err := os.WriteFile(filepath.Join(dir, "file"), []byte("data"), 0o644); err != nil {
+			t.Fatalf("failed to write file: %v", err)
+		}
+
+		check := CheckPath("Workspace directory", dir, true)
+		if check.Missing {
+			t.Error("Missing = true, want false")
+		}
+		if !check.OK() {
+			t.Errorf("OK() = false, want true (problems: %v)", check.Problems)
+		}
+	})
+
+	t.Run("read-only directory", func(t *testing.T) {
+		if runtime.GOOS == "windows" {
+			t.Skip("access checks are not supported on windows")
+		}
+		if os.Getuid() == 0 {
+			t.Skip("root bypasses permission checks")
+		}
+
+		dir := t.TempDir()
+		if err := os.Chmod(dir, 0o500); err != nil {
+			t.Fatalf("failed to chmod directory: %v", err)
+		}
+		//nolint:errcheck // Test cleanup
+		defer os.Chmod(dir, 0o755)
+
+		check := CheckPath("Workspace directory", dir, false)
+		if check.OK() {
+			t.Fatal("OK() = true, want false")
+		}
+		if len(check.Fixes) != 1 || !strings.HasPrefix(check.Fixes[0], "chmod u+rwx ") {
+			t.Errorf("Fixes = %v, want a single chmod u+rwx fix", check.Fixes)
+		}
+	})
+}
+
+func TestPathCheck_addProblem(t *testing.T) {
+	check := &PathCheck{}
+	check.addProblem("first", "sudo chown -R user /path")
+	check.addProblem("second", "sudo chown -R user /path")
+
+	if len(check.Problems) != 2 {
+		t.Errorf("len(Problems) = %d, want 2", len(check.Problems))
+	}
+	if len(check.Fixes) != 1 {
+		t.Errorf("len(Fixes) = %d, want 1", len(check.Fixes))
+	}
+}
+
+func TestChownFix(t *testing.T) {
+	name := userName(os.Getuid())
+
+	got := chownFix("/tmp/ws", true)
+	want := "sudo chown -R " + name + " /tmp/ws"
+	if got != want {
+		t.Errorf("chownFix(recursive) = %q, want %q", got, want)
+	}
+
+	got = chownFix("/tmp/ws", false)
+	want = "sudo chown " + name + " /tmp/ws"
+	if got != want {
+		t.Errorf("chownFix() = %q, want %q", got, want)
+	}
+
+	got = chownFix("/tmp/$USER ws", true)
+	want = "sudo chown -R " + name + " '/tmp/$USER ws'"
+	if got != want {
+		t.Errorf("chownFix(special characters) = %q, want %q", got, want)
+	}
+}
+
+func TestFindProblemEntry(t *testing.T) {
+	uid := os.Getuid()
+
+	t.Run("clean tree", func(t *testing.T) {
+		dir := t.TempDir()
+		if err := os.MkdirAll(filepath.Join(dir, "a", "b"), 0o755); err != nil {
+			t.Fatalf("failed to create directories: %v", err)
+		}
+		if entry, problem := findProblemEntry(dir, uid); entry != "" {
+			t.Errorf("findProblemEntry() = %q (%s), want no problem", entry, problem)
+		}
+	})
+
+	t.Run("unreadable directory", func(t *testing.T) {
+		if runtime.GOOS == "windows" {
+			t.Skip("permissions are not enforced on windows")
+		}
+		if uid == 0 {
+			t.Skip("root bypasses permission checks")
+		}
+
+		dir := t.TempDir()
+		locked := filepath.Join(dir, "locked")
+		if err := os.Mkdir(locked, 0o000); err != nil {
+			t.Fatalf("failed to create directory: %v", err)
+		}
+		//nolint:errcheck // Test cleanup
+		defer os.Chmod(locked, 0o755)
+
+		entry, problem := findProblemEntry(dir, uid)
+		if entry != locked || problem != "cannot be read" {
+			t.Errorf("findProblemEntry() = %q (%s), want %q (cannot be read)", entry, problem, locked)
+		}
+	})
+
+	t.Run("skips dependency and deep directories", func(t *testing.T) {
+		if runtime.GOOS == "windows" {
+			t.Skip("permissions are not enforced on windows")
+		}
+		if uid == 0 {
+			t.Skip("root bypasses permission checks")
+		}
+
+		dir := t.TempDir()
+		deep := filepath.Join(dir, "a", "b", "c", "d")
+		for _, locked := range []string{filepath.Join(dir, "node_modules", "pkg"), filepath.Join(deep, "e")} {
+			if err := os.MkdirAll(locked, 0o755); err != nil {
+				t.Fatalf("failed to create directory: %v", err)
+			}
+			if err := os.Chmod(locked, 0o000); err != nil {
+				t.Fatalf("failed to chmod directory: %v", err)
+			}
+			//nolint:errcheck // Test cleanup
+			defer os.Chmod(locked, 0o755)
+		}
+
+		if entry, problem := findProblemEntry(dir, uid); entry != "" {
+			t.Errorf("findProblemEntry() = %q (%s), want no problem", entry, problem)
+		}
+	})
+}
@@ -0,0 +1,34 @@
+//go:build unix
+
+package doctor
+
+import (
+	"os"
+	"syscall"
+)
+
+// Access mode bits for syscall.Access (see access(2))
+const (
+	accessExec  = 0x1
+	accessWrite = 0x2
+	accessRead  = 0x4
+)
+
+// fileOwner returns the uid owning the file
+func fileOwner(info os.FileInfo) (int, bool) {
+	stat, ok := info.Sys().(*syscall.Stat_t)
+	if !ok {
+		return 0, false
+	}
+	return int(stat.Uid), true
+}
+
+// checkAccess checks that the current user can read and write path, and
+// traverse it if it is a directory
+func checkAccess(path string, isDir bool) error {
+	mode := uint32(accessRead | accessWrite)
+	if isDir {
+		mode |= accessExec
+	}
+	return syscall.Access(path, mode)
+}
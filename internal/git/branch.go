@@ -353,3 +353,12 @@ func parseGitBranchList(output string) []string {
 	}
 	return branches
 }
+
+// ValidateBranchName checks that name is a valid branch name using 'git check-ref-format'
+func ValidateBranchName(name string) error {
+	cmd := exec.Command("git", "check-ref-format", "--branch", name)
+	if output, err := cmd.CombinedOutput(); err != nil {
+		return eris.Wrapf(err, "invalid branch name %q: %s", name, strings.TrimSpace(string(output)))
+	}
+	return nil
+}
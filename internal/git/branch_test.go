@@ -1,6 +1,7 @@
 package git
 
 import (
+	"os/exec"
 	"testing"
 )
 
@@ -73,3 +74,30 @@ func TestParseBranchInfo(t *testing.T) {
 		})
 	}
 }
+
+func TestValidateBranchName(t *testing.T) {
+	if _, err := exec.LookPath("git"); err != nil {
+		t.Skip("git not available")
+	}
+
+	tests := []struct {
+		name    string
+		branch  string
+		wantErr bool
+	}{
+		{name: "generated name", branch: "alice/2025-01-31-ABC-123-fix-login", wantErr: false},
+		{name: "version in slug", branch: "alice/2025-01-31-bump-v1.2", wantErr: false},
+		{name: "double dot", branch: "alice/2025-01-31-v1..2", wantErr: true},
+		{name: "lock suffix", branch: "alice/2025-01-31-foo.lock", wantErr: true},
+		{name: "trailing slash", branch: "alice/", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			err := ValidateBranchName(tt.branch)
+			if (err != nil) != tt.wantErr {
+				t.Errorf("ValidateBranchName(%q) error = %v, wantErr %v", tt.branch, err, tt.wantErr)
+			}
+		})
+	}
+}
@@ -3,15 +3,53 @@ package git
 import (
 	"fmt"
 	"net/url"
+	"os"
 	"os/exec"
 	"path/filepath"
 	"strings"
+	"time"
 
 	"github.com/rotisserie/eris"
 )
 
 // Clone clones a git repository as a bare repository to the specified destination path
+// The clone is performed in a temporary sibling directory and atomically renamed into
+// place on success, so an interrupted or failed clone never leaves a half-populated
+// directory at destPath
 func Clone(remoteURL, destPath string) error {
+	if _, err := os.Stat(destPath); err == nil {
+		return eris.Errorf("destination already exists: %s", destPath)
+	}
+
+	parentDir := filepath.Dir(destPath)
+	if err := os.MkdirAll(parentDir, 0o755); err != nil {
+		return eris.Wrapf(err, "failed to create parent directory: %s", parentDir)
+	}
+
+	// Remove leftovers from previously interrupted clones of the same repository
+	removeStaleCloneDirs(destPath, staleCloneAge)
+
+	tmpPath, err := os.MkdirTemp(parentDir, cloneTempPrefix(destPath))
+	if err != nil {
+		return eris.Wrap(err, "failed to create temporary clone directory")
+	}
+
+	if err := cloneBare(remoteURL, tmpPath); err != nil {
+		_ = os.RemoveAll(tmpPath)
+		return err
+	}
+
+	if err := os.Rename(tmpPath, destPath); err != nil {
+		_ = os.RemoveAll(tmpPath)
+		return eris.Wrapf(err, "failed to move clone into place: %s", destPath)
+	}
+
+	return nil
+}
+
+// cloneBare clones a repository as a bare repository into destPath and configures
+// remote-tracking branches
+func cloneBare(remoteURL, destPath string) error {
 	cmd := exec.Command("git", "clone", "--bare", remoteURL, destPath)
 	output, err := cmd.CombinedOutput()
 	if err != nil {
@@ -37,6 +75,37 @@ func Clone(remoteURL, destPath string) error {
 	return nil
 }
 
+// cloneTempPrefix returns the name prefix used for temporary clone directories of destPath
+// The prefix is hidden and doesn't end in .git, so in-progress clones are never
+// discovered as projects
+// Example: ~/.sesh/github.com/user/repo.git -> .repo.git.clone-
+func cloneTempPrefix(destPath string) string {
+	return "." + filepath.Base(destPath) + ".clone-"
+}
+
+// staleCloneAge is how old a temporary clone directory must be before it is
+// considered abandoned. Younger directories may belong to a clone still running
+// in another sesh process
+const staleCloneAge = 24 * time.Hour
+
+// removeStaleCloneDirs removes temporary clone directories of destPath that were
+// last modified more than olderThan ago. This is best-effort; errors are ignored
+func removeStaleCloneDirs(destPath string, olderThan time.Duration) {
+	pattern := filepath.Join(filepath.Dir(destPath), cloneTempPrefix(destPath)+"*")
+	matches, err := filepath.Glob(pattern)
+	if err != nil {
+		return
+	}
+	cutoff := time.Now().Add(-olderThan)
+	for _, match := range matches {
+		info, err := os.Stat(match)
+		if err != nil || info.ModTime().After(cutoff) {
+			continue
+		}
+		_ = os.RemoveAll(match)
+	}
+}
+
 // GetRemoteURL retrieves the remote URL from a git repository
 func GetRemoteURL(repoPath string) (string, error) {
 	cmd := exec.Command("git", "-C", repoPath, "remote", "get-url", "origin")
@@ -1,7 +1,10 @@
 package git
 
 import (
+	"os"
+	"path/filepath"
 	"testing"
+	"time"
 )
 
 func TestParseRemoteURL(t *testing.T) {
@@ -257,3 +260,71 @@ func TestParseGitBranchList(t *testing.T) {
 		})
 	}
 }
+
+func TestCloneTempPrefix(t *testing.T) {
+	got := cloneTempPrefix("/home/user/.sesh/github.com/user/repo.git")
+	want := ".repo.git.clone-"
+	if got != want {
+		t.Errorf("cloneTempPrefix() = %q, want %q", got, want)
+	}
+}
+
+func TestRemoveStaleCloneDirs(t *testing.T) {
+	parentDir := t.TempDir()
+	destPath := filepath.Join(parentDir, "repo.git")
+
+	stale := filepath.Join(parentDir, cloneTempPrefix(destPath)+"12345")
+	inProgress := filepath.Join(parentDir, cloneTempPrefix(destPath)+"67890")
+	unrelated := filepath.Join(parentDir, "other.git")
+	for _, dir := range []string{stale, inProgress, unrelated} {
+		if err := os.MkdirAll(dir, 0o755); err != nil {
+			t.Fatalf("failed to create directory %s: %v", dir, err)
+		}
+	}
+
+	old := time.Now().Add(-2 * time.Hour)
+	if err := os.Chtimes(stale, old, old); err != nil {
+		t.Fatalf("failed to age directory %s: %v", stale, err)
+	}
+
+	removeStaleCloneDirs(destPath, time.Hour)
+
+	if _, err := os.Stat(stale); !os.IsNotExist(err) {
+		t.Errorf("stale clone directory %s was not removed", stale)
+	}
+	if _, err := os.Stat(inProgress); err != nil {
+		t.Errorf("in-progress clone directory %s was removed: %v", inProgress, err)
+	}
+	if _, err := os.Stat(unrelated); err != nil {
+		t.Errorf("unrelated directory %s was removed: %v", unrelated, err)
+	}
+}
+
+func TestClone_FailureLeavesNoDirectories(t *testing.T) {
+	parentDir := t.TempDir()
+	destPath := filepath.Join(parentDir, "repo.git")
+
+	err := Clone(filepath.Join(parentDir, "does-not-exist"), destPath)
+	if err == nil {
+		t.Fatal("Clone() returned nil, want error")
+	}
+
+	entries, err := os.ReadDir(parentDir)
+	if err != nil {
+		t.Fatalf("failed to read parent directory: %v", err)
+	}
+	if len(entries) != 0 {
+		t.Errorf("Clone() left %d entries behind after failure, want 0", len(entries))
+	}
+}
+
+func TestClone_DestinationExists(t *testing.T) {
+	destPath := filepath.Join(t.TempDir(), "repo.git")
+	if err := os.MkdirAll(destPath, 0o755); err != nil {
+		t.Fatalf("failed to create destination: %v", err)
+	}
+
+	if err := Clone("https://github.com/user/repo.git", destPath); err == nil {
+		t.Error("Clone() returned nil, want error for existing destination")
+	}
+}
@@ -0,0 +1,206 @@
+package git
+
+import (
+	"fmt"
+	"os/exec"
+	"strings"
+
+	"github.com/benoctopus/sesh/internal/shell"
+	"github.com/rotisserie/eris"
+)
+
+// UpdateOperation is the git operation used to bring a branch up to date
+type UpdateOperation string
+
+const (
+	UpdateRebase UpdateOperation = "rebase"
+	UpdateMerge  UpdateOperation = "merge"
+)
+
+// ConflictFile describes a single conflicted file in a worktree
+type ConflictFile struct {
+	Path   string `json:"path"`
+	Code   string `json:"code"`   // Porcelain XY status code (e.g. "UU")
+	Status string `json:"status"` // Human readable status (e.g. "both modified")
+	Hint   string `json:"hint"`   // Suggested way to resolve the conflict
+}
+
+// ConflictReport describes a rebase or merge that stopped because of conflicts
+// and the state the worktree was left in
+type ConflictReport struct {
+	Operation    UpdateOperation `json:"operation"`
+	WorktreePath string          `json:"worktree_path"`
+	Branch       string          `json:"branch"`
+	Onto         string          `json:"onto"`
+	Ours         string          `json:"ours"`   // What "--ours" refers to during this operation
+	Theirs       string          `json:"theirs"` // What "--theirs" refers to during this operation
+	State        string          `json:"state"`
+	Files        []ConflictFile  `json:"files"`
+	NextSteps    []string        `json:"next_steps"`
+}
+
+// conflictStatuses maps porcelain XY codes for unmerged paths to a description
+// See "git status --help" under "Short Format"
+var conflictStatuses = map[string]string{
+	"DD": "both deleted",
+	"AU": "added by us",
+	"UD": "deleted by them",
+	"UA": "added by them",
+	"DU": "deleted by us",
+	"AA": "both added",
+	"UU": "both modified",
+}
+
+// Rebase rebases branch, checked out in worktreePath, onto the given ref
+// Returns a ConflictReport if the rebase stopped because of conflicts
+func Rebase(worktreePath, branch, onto string) (*ConflictReport, error) {
+	return runUpdate(UpdateRebase, worktreePath, branch, onto)
+}
+
+// Merge merges the given ref into branch, checked out in worktreePath
+// Returns a ConflictReport if the merge stopped because of conflicts
+func Merge(worktreePath, branch, ref string) (*ConflictReport, error) {
+	return runUpdate(UpdateMerge, worktreePath, branch, ref)
+}
+
+// runUpdate runs a rebase or merge and builds a ConflictReport if it stops on conflicts
+// The branch is passed in because HEAD is detached while a rebase is stopped
+func runUpdate(op UpdateOperation, worktreePath, branch, onto string) (*ConflictReport, error) {
+	args := []string{"-C", worktreePath, string(op)}
+	if op == UpdateMerge {
+		args = append(args, "--no-edit")
+	}
+	args = append(args, onto)
+
+	cmd := exec.Command("git", args...)
+	output, err := cmd.CombinedOutput()
+	if err == nil {
+		return nil, nil
+	}
+
+	files, listErr := ListConflicts(worktreePath)
+	if listErr != nil || len(files) == 0 {
+		return nil, eris.Wrapf(err, "failed to %s onto %s: %s", op, onto, string(output))
+	}
+
+	return newConflictReport(op, worktreePath, branch, onto, files), nil
+}
+
+// HasTrackedChanges reports whether a worktree has staged or unstaged changes to tracked files
+// Untracked files are ignored since they don't get in the way of a rebase or merge
+func HasTrackedChanges(worktreePath string) (bool, error) {
+	cmd := exec.Command("git", "-C", worktreePath, "status", "--porcelain", "--untracked-files=no")
+	output, err := cmd.Output()
+	if err != nil {
+		return false, eris.Wrap(err, "failed to get git status")
+	}
+	return len(strings.TrimSpace(string(output))) > 0, nil
+}
+
+// ListConflicts returns the unmerged (conflicted) files in a worktree
+func ListConflicts(worktreePath string) ([]ConflictFile, error) {
+	cmd := exec.Command("git", "-C", worktreePath, "status", "--porcelain", "-z")
+	output, err := cmd.Output()
+	if err != nil {
+		return nil, eris.Wrap(err, "failed to get git status")
+	}
+
+	return parseConflicts(string(output)), nil
+}
+
+// parseConflicts extracts conflicted files from 'git status --porcelain -z' output
+// With -z, entries are NUL-terminated and paths are never quoted. Renames and
+// copies are followed by an extra entry holding the original path
+func parseConflicts(output string) []ConflictFile {
+	var files []ConflictFile
+	entries := strings.Split(output, "\x00")
+	for i := 0; i < len(entries); i++ {
+		entry := entries[i]
+		if len(entry) < 4 {
+			continue
+		}
+
+		code := entry[:2]
+		if code[0] == 'R' || code[0] == 'C' {
+			// Skip the original path of the rename/copy
+			i++
+			continue
+		}
+
+		status, ok := conflictStatuses[code]
+		if !ok {
+			continue
+		}
+
+		files = append(files, ConflictFile{
+			Path:   entry[3:],
+			Code:   code,
+			Status: status,
+			Hint:   conflictHint(code),
+		})
+	}
+	return files
+}
+
+// conflictHint returns a suggestion for resolving a conflict of the given kind
+func conflictHint(code string) string {
+	switch code {
+	case "UU", "AA":
+		return "edit the file to resolve conflict markers, or take one side with 'git checkout --ours/--theirs'"
+	case "DU", "UD":
+		return "keep the file with 'git add', or accept the deletion with 'git rm'"
+	case "AU", "UA":
+		return "keep the added file with 'git add', or drop it with 'git rm'"
+	case "DD":
+		return "accept the deletion with 'git rm'"
+	default:
+		return "resolve the conflict, then 'git add' the file"
+	}
+}
+
+// newConflictReport builds a ConflictReport including which side is "ours"/"theirs"
+// and the commands needed to continue or abort the operation
+func newConflictReport(
+	op UpdateOperation,
+	worktreePath, branch, onto string,
+	files []ConflictFile,
+) *ConflictReport {
+	report := &ConflictReport{
+		Operation:    op,
+		WorktreePath: worktreePath,
+		Branch:       branch,
+		Onto:         onto,
+		Files:        files,
+	}
+
+	// During a rebase the sides are swapped: "ours" is the branch being rebased onto
+	// and "theirs" is the commit of yours being replayed
+	switch op {
+	case UpdateRebase:
+		report.Ours = onto
+		report.Theirs = branch
+		report.State = fmt.Sprintf(
+			"rebase of %s onto %s is in progress and stopped with %d conflicted file(s); "+
+				"the worktree contains conflict markers",
+			branch, onto, len(files),
+		)
+	case UpdateMerge:
+		report.Ours = branch
+		report.Theirs = onto
+		report.State = fmt.Sprintf(
+			"merge of %s into %s is in progress and stopped with %d conflicted file(s); "+
+				"the worktree contains conflict markers",
+			onto, branch, len(files),
+		)
+	}
+
+	gitCmd := fmt.Sprintf("git -C %s", shell.Quote(worktreePath))
+	report.NextSteps = []string{
+		fmt.Sprintf("%s status", gitCmd),
+		fmt.Sprintf("%s add <file>   # after resolving each file", gitCmd),
+		fmt.Sprintf("%s %s --continue", gitCmd, op),
+		fmt.Sprintf("%s %s --abort   # to return to the state before the %s", gitCmd, op, op),
+	}
+
+	return report
+}
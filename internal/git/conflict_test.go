@@ -0,0 +1,172 @@
+package git
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+)
+
+func TestParseConflicts(t *testing.T) {
+	tests := []struct {
+		name   string
+		output string
+		want   []ConflictFile
+	}{
+		{
+			name:   "no conflicts",
+			output: " M main.go\x00?? notes.txt\x00A  new.go\x00",
+			want:   nil,
+		},
+		{
+			name:   "mixed conflicts and changes",
+			output: "UU cmd/root.go\x00 M main.go\x00DU README.md\x00AA go.mod\x00",
+			want: []ConflictFile{
+				{Path: "cmd/root.go", Code: "UU", Status: "both modified", Hint: conflictHint("UU")},
+				{Path: "README.md", Code: "DU", Status: "deleted by us", Hint: conflictHint("DU")},
+				{Path: "go.mod", Code: "AA", Status: "both added", Hint: conflictHint("AA")},
+			},
+		},
+		{
+			name:   "paths are not unquoted or split",
+			output: "UU docs/my notes.md\x00UU \"quoted\".txt\x00",
+			want: []ConflictFile{
+				{Path: "docs/my notes.md", Code: "UU", Status: "both modified", Hint: conflictHint("UU")},
+				{Path: "\"quoted\".txt", Code: "UU", Status: "both modified", Hint: conflictHint("UU")},
+			},
+		},
+		{
+			name:   "original path of a rename is skipped",
+			output: "R  new.go\x00UU old.go\x00AA go.mod\x00",
+			want: []ConflictFile{
+				{Path: "go.mod", Code: "AA", Status: "both added", Hint: conflictHint("AA")},
+			},
+		},
+		{
+			name:   "empty output",
+			output: "",
+			want:   nil,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := parseConflicts(tt.output)
+			if len(got) != len(tt.want) {
+				t.Fatalf("parseConflicts() returned %d files, want %d", len(got), len(tt.want))
+			}
+			for i := range got {
+				if got[i] != tt.want[i] {
+					t.Errorf("parseConflicts()[%d] = %+v, want %+v", i, got[i], tt.want[i])
+				}
+			}
+		})
+	}
+}
+
+func TestNewConflictReport(t *testing.T) {
+	files := []ConflictFile{{Path: "main.go", Code: "UU", Status: "both modified"}}
+
+	t.Run("rebase swaps ours and theirs", func(t *testing.T) {
+		report := newConflictReport(UpdateRebase, "/tmp/wt", "feature", "origin/main", files)
+		if report.Ours != "origin/main" {
+			t.Errorf("Ours = %q, want %q", report.Ours, "origin/main")
+		}
+		if report.Theirs != "feature" {
+			t.Errorf("Theirs = %q, want %q", report.Theirs, "feature")
+		}
+		want := "git -C /tmp/wt rebase --continue"
+		if report.NextSteps[2] != want {
+			t.Errorf("NextSteps[2] = %q, want %q", report.NextSteps[2], want)
+		}
+	})
+
+	t.Run("merge keeps ours as the current branch", func(t *testing.T) {
+		report := newConflictReport(UpdateMerge, "/tmp/wt", "feature", "origin/main", files)
+		if report.Ours != "feature" {
+			t.Errorf("Ours = %q, want %q", report.Ours, "feature")
+		}
+		if report.Theirs != "origin/main" {
+			t.Errorf("Theirs = %q, want %q", report.Theirs, "origin/main")
+		}
+		want := "git -C /tmp/wt merge --abort   # to return to the state before the merge"
+		if report.NextSteps[3] != want {
+			t.Errorf("NextSteps[3] = %q, want %q", report.NextSteps[3], want)
+		}
+	})
+}
+
+// newConflictingRepo creates a repository where branches "main" and "feature" both
+// change the same line of file.txt, with "feature" checked out
+func newConflictingRepo(t *testing.T) string {
+	t.Helper()
+	if _, err := exec.LookPath("git"); err != nil {
+		t.Skip("git not available")
+	}
+	t.Setenv("GIT_AUTHOR_NAME", "test")
+	t.Setenv("GIT_AUTHOR_EMAIL", "test@example.com")
+	t.Setenv("GIT_COMMITTER_NAME", "test")
+	t.Setenv("GIT_COMMITTER_EMAIL", "test@example.com")
+
+	dir := t.TempDir()
+	runGit := func(args ...string) {
+		t.Helper()
+		cmd := exec.Command("git", append([]string{"-C", dir}, args...)...)
+		if output, err := cmd.CombinedOutput(); err != nil {
+			t.Fatalf("git %v failed: %v\n%s", args, err, output)
+		}
+	}
+	commitFile := func(content, message string) {
+		t.Helper()
+		if err := os.WriteFile(filepath.Join(dir, "file.txt"), []byte(content), 0o644); err != nil {
+			t.Fatalf("failed to write file: %v", err)
+		}
+		runGit("add", "file.txt")
+		runGit("commit", "-q", "-m", message)
+	}
+
+	runGit("init", "-q", "-b", "main")
+	commitFile("base\n", "base")
+	runGit("checkout", "-q", "-b", "feature")
+	commitFile("feature\n", "feature change")
+	runGit("checkout", "-q", "main")
+	commitFile("main\n", "main change")
+	runGit("checkout", "-q", "feature")
+
+	return dir
+}
+
+func TestUpdate_Conflict(t *testing.T) {
+	tests := []struct {
+		name       string
+		update     func(worktreePath, branch, onto string) (*ConflictReport, error)
+		wantOurs   string
+		wantTheirs string
+	}{
+		{name: "rebase", update: Rebase, wantOurs: "main", wantTheirs: "feature"},
+		{name: "merge", update: Merge, wantOurs: "feature", wantTheirs: "main"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			dir := newConflictingRepo(t)
+
+			report, err := tt.update(dir, "feature", "main")
+			if err != nil {
+				t.Fatalf("update error = %v", err)
+			}
+			if report == nil {
+				t.Fatal("update returned no conflict report")
+			}
+			if report.Branch != "feature" {
+				t.Errorf("Branch = %q, want %q", report.Branch, "feature")
+			}
+			if report.Ours != tt.wantOurs || report.Theirs != tt.wantTheirs {
+				t.Errorf("Ours/Theirs = %q/%q, want %q/%q", report.Ours, report.Theirs, tt.wantOurs, tt.wantTheirs)
+			}
+			if len(report.Files) != 1 || report.Files[0].Path != "file.txt" || report.Files[0].Code != "UU" {
+				t.Errorf("Files = %+v, want a single UU file.txt", report.Files)
+			}
+		})
+	}
+}
@@ -90,6 +90,11 @@ func (e *EditorManager) GetCurrentSessionName() (string, error) {
 	return "", eris.Errorf("getting current session is not supported with the %s backend", e.Name())
 }
 
+// Send returns an error as editor backends have no terminal to send text to
+func (e *EditorManager) Send(name, text string) error {
+	return eris.Wrapf(ErrSendUnsupported, "%s backend", e.Name())
+}
+
 // openPath opens the given path in the editor using the configured mode
 func (e *EditorManager) openPath(path string) error {
 	args := e.buildArgs(path)
@@ -2,6 +2,8 @@ package session
 
 import (
 	"testing"
+
+	"github.com/rotisserie/eris"
 )
 
 func TestNewEditorManager(t *testing.T) {
@@ -111,6 +113,14 @@ func TestEditorManager_GetCurrentSessionName(t *testing.T) {
 	}
 }
 
+func TestEditorManager_Send(t *testing.T) {
+	mgr := NewEditorManager("code", EditorModeOpen)
+	err := mgr.Send("test", "echo hello")
+	if !eris.Is(err, ErrSendUnsupported) {
+		t.Errorf("Send() = %v, want ErrSendUnsupported", err)
+	}
+}
+
 func TestEditorManager_buildArgs(t *testing.T) {
 	tests := []struct {
 		mode EditorMode
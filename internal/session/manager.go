@@ -7,6 +7,9 @@ import (
 	"github.com/rotisserie/eris"
 )
 
+// ErrSendUnsupported is returned by Send when the backend has no terminal to send text to
+var ErrSendUnsupported = eris.New("sending text is not supported by this session backend")
+
 // SessionManager defines the interface that all session backends must implement
 type SessionManager interface {
 	// Create creates a new session with the given name at the specified path
@@ -38,6 +41,9 @@ type SessionManager interface {
 
 	// GetCurrentSessionName returns the name of the current session, or empty string if not in a session
 	GetCurrentSessionName() (string, error)
+
+	// Send sends text to a session followed by Enter, running it as a command
+	Send(name, text string) error
 }
 
 // BackendType represents the type of session backend
@@ -198,6 +204,10 @@ func (n *NoneManager) GetCurrentSessionName() (string, error) {
 	return "", nil
 }
 
+func (n *NoneManager) Send(name, text string) error {
+	return eris.Wrap(ErrSendUnsupported, "no session manager available")
+}
+
 // IsInsideTmux checks if the current process is running inside tmux
 func IsInsideTmux() bool {
 	return os.Getenv("TMUX") != ""
@@ -3,6 +3,8 @@ package session
 import (
 	"os"
 	"testing"
+
+	"github.com/rotisserie/eris"
 )
 
 func TestGetBackendName(t *testing.T) {
@@ -91,6 +93,13 @@ func TestNoneManager(t *testing.T) {
 		}
 	})
 
+	t.Run("Send returns error", func(t *testing.T) {
+		err := mgr.Send("test", "echo hello")
+		if !eris.Is(err, ErrSendUnsupported) {
+			t.Errorf("Send() = %v, want ErrSendUnsupported", err)
+		}
+	})
+
 	t.Run("List returns empty", func(t *testing.T) {
 		sessions, err := mgr.List()
 		if err != nil {
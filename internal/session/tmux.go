@@ -253,8 +253,8 @@ func (t *TmuxManager) Rename(oldName, newName string) error {
 	return nil
 }
 
-// SendKeys sends keys/commands to a tmux session
-func (t *TmuxManager) SendKeys(name, command string) error {
+// Send sends text to a tmux session followed by Enter using send-keys
+func (t *TmuxManager) Send(name, text string) error {
 	// Check if session exists
 	exists, err := t.Exists(name)
 	if err != nil {
@@ -264,12 +264,19 @@ func (t *TmuxManager) SendKeys(name, command string) error {
 		return eris.Errorf("session '%s' does not exist", name)
 	}
 
-	// Send the command to the session
-	cmd := exec.Command("tmux", "send-keys", "-t", name, command, "C-m")
+	// Send the text literally (-l) so words like "Enter" aren't treated as key names,
+	// then press Enter separately to run it
+	cmd := exec.Command("tmux", "send-keys", "-t", name, "-l", text)
 	output, err := cmd.CombinedOutput()
 	if err != nil {
 		return eris.Wrapf(err, "failed to send keys to tmux session: %s", string(output))
 	}
 
+	cmd = exec.Command("tmux", "send-keys", "-t", name, "C-m")
+	output, err = cmd.CombinedOutput()
+	if err != nil {
+		return eris.Wrapf(err, "failed to send keys to tmux session: %s", string(output))
+	}
+
 	return nil
 }
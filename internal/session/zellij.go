@@ -251,8 +251,8 @@ func (z *ZellijManager) CreateOrAttach(name, path string) error {
 	return z.Attach(name)
 }
 
-// SendKeys sends commands to a zellij session
-func (z *ZellijManager) SendKeys(name, command string) error {
+// Send sends text to a zellij session followed by Enter
+func (z *ZellijManager) Send(name, text string) error {
 	// Check if session exists
 	exists, err := z.Exists(name)
 	if err != nil {
@@ -263,13 +263,19 @@ func (z *ZellijManager) SendKeys(name, command string) error {
 	}
 
 	// Zellij doesn't have a direct equivalent to tmux send-keys
-	// We can use zellij action write to send text to the current pane
-	// Note: This is a limitation compared to tmux
-	cmd := exec.Command("zellij", "action", "write", "27", command) // 27 is the escape key code
+	// We target the session explicitly and write the text to its focused pane
+	cmd := exec.Command("zellij", "--session", name, "action", "write-chars", text)
 	output, err := cmd.CombinedOutput()
 	if err != nil {
 		return eris.Wrapf(err, "failed to send keys to zellij session: %s", string(output))
 	}
 
+	// Press Enter (byte 13) to run the text as a command
+	cmd = exec.Command("zellij", "--session", name, "action", "write", "13")
+	output, err = cmd.CombinedOutput()
+	if err != nil {
+		return eris.Wrapf(err, "failed to send keys to zellij session: %s", string(output))
+	}
+
 	return nil
 }
@@ -0,0 +1,18 @@
+package shell
+
+import (
+	"regexp"
+	"strings"
+)
+
+// safeChars matches strings that can be pasted into a shell without quoting
+var safeChars = regexp.MustCompile(`^[A-Za-z0-9_@%+=:,./-]+$`)
+
+// Quote quotes s for copy-pasting into a POSIX shell, leaving simple strings as-is
+// Single quotes disable all expansion, so "$", "`" and "\" are kept literally
+func Quote(s string) string {
+	if safeChars.MatchString(s) {
+		return s
+	}
+	return "'" + strings.ReplaceAll(s, "'", `'\''`) + "'"
+}
@@ -0,0 +1,27 @@
+package shell
+
+import (
+	"testing"
+)
+
+func TestQuote(t *testing.T) {
+	tests := []struct {
+		name  string
+		input string
+		want  string
+	}{
+		{name: "plain path", input: "/home/user/.sesh/repo/main", want: "/home/user/.sesh/repo/main"},
+		{name: "spaces", input: "/tmp/my wt", want: "'/tmp/my wt'"},
+		{name: "single quote", input: "/tmp/it's", want: `'/tmp/it'\''s'`},
+		{name: "expansion characters", input: "/tmp/$HOME/`id`/a\\b", want: "'/tmp/$HOME/`id`/a\\b'"},
+		{name: "empty", input: "", want: "''"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := Quote(tt.input); got != tt.want {
+				t.Errorf("Quote(%q) = %q, want %q", tt.input, got, tt.want)
+			}
+		})
+	}
+}
@@ -1,11 +1,14 @@
 package workspace
 
 import (
+	"bytes"
 	"fmt"
 	"os"
 	"path/filepath"
 	"regexp"
 	"strings"
+	"text/template"
+	"time"
 
 	"github.com/rotisserie/eris"
 )
@@ -98,6 +101,70 @@ func SanitizeBranchName(branch string) string {
 	return sanitized
 }
 
+// BranchNameData holds the values available to branch name templates
+type BranchNameData struct {
+	User   string // Current user name, e.g. "alice"
+	Date   string // Current date in YYYY-MM-DD format
+	Ticket string // Optional ticket identifier, e.g. "ABC-123"
+	Slug   string // Optional slug of a short description, e.g. "fix-login-bug"
+}
+
+// NewBranchNameData builds template data for the given user, time, ticket and description
+// The user, ticket and description are slugified so the result is a valid branch name
+func NewBranchNameData(userName string, now time.Time, ticket, description string) BranchNameData {
+	return BranchNameData{
+		User:   strings.ToLower(Slugify(userName)),
+		Date:   now.Format("2006-01-02"),
+		Ticket: Slugify(ticket),
+		Slug:   strings.ToLower(Slugify(description)),
+	}
+}
+
+// GenerateBranchName renders a branch name template
+// Example: "{{.User}}/{{.Date}}{{with .Slug}}-{{.}}{{end}}" -> "alice/2025-01-31-fix-login"
+func GenerateBranchName(branchTemplate string, data BranchNameData) (string, error) {
+	tmpl, err := template.New("branch").Parse(branchTemplate)
+	if err != nil {
+		return "", eris.Wrap(err, "failed to parse branch template")
+	}
+
+	var buf bytes.Buffer
+	if err := tmpl.Execute(&buf, data); err != nil {
+		return "", eris.Wrap(err, "failed to execute branch template")
+	}
+
+	branch := strings.Trim(strings.TrimSpace(buf.String()), "/-")
+	if branch == "" {
+		return "", eris.Errorf("branch template %q produced an empty branch name", branchTemplate)
+	}
+
+	return branch, nil
+}
+
+// maxSlugLength is the maximum length of a slug produced by Slugify
+const maxSlugLength = 40
+
+// slugInvalidChars matches runs of characters not allowed in slugs
+var slugInvalidChars = regexp.MustCompile(`[^A-Za-z0-9._]+`)
+
+// Slugify converts free text into a string that is safe to use in a branch name
+// Runs of characters other than letters, digits, "." and "_" become a single hyphen
+// Examples:
+//   - "Fix login bug!" -> "Fix-login-bug"
+//   - "ABC-123" -> "ABC-123"
+//   - "DOMAIN\alice" -> "DOMAIN-alice"
+func Slugify(text string) string {
+	slug := slugInvalidChars.ReplaceAllString(text, "-")
+	slug = strings.Trim(slug, "-.")
+
+	// Keep generated names short enough to type and to fit in session names
+	if len(slug) > maxSlugLength {
+		slug = strings.TrimRight(slug[:maxSlugLength], "-.")
+	}
+
+	return slug
+}
+
 // ParseSessionName parses a session name back into repository and branch
 // Format: <repoName>-<branch>
 // Returns: repoName, branch, error
@@ -2,7 +2,9 @@ package workspace
 
 import (
 	"path/filepath"
+	"strings"
 	"testing"
+	"time"
 )
 
 func TestSanitizeBranchName(t *testing.T) {
@@ -427,3 +429,113 @@ func TestCleanPath(t *testing.T) {
 		})
 	}
 }
+
+func TestSlugify(t *testing.T) {
+	tests := []struct {
+		name     string
+		input    string
+		expected string
+	}{
+		{
+			name:     "description with spaces and punctuation",
+			input:    "Fix login bug!",
+			expected: "Fix-login-bug",
+		},
+		{
+			name:     "ticket identifier",
+			input:    "ABC-123",
+			expected: "ABC-123",
+		},
+		{
+			name:     "windows domain user",
+			input:    "DOMAIN\\alice",
+			expected: "DOMAIN-alice",
+		},
+		{
+			name:     "leading and trailing separators",
+			input:    "  --hello world.. ",
+			expected: "hello-world",
+		},
+		{
+			name:     "empty",
+			input:    "",
+			expected: "",
+		},
+		{
+			name:     "truncated to max length",
+			input:    strings.Repeat("a", 50),
+			expected: strings.Repeat("a", maxSlugLength),
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			result := Slugify(tt.input)
+			if result != tt.expected {
+				t.Errorf("Slugify(%q) = %q, want %q", tt.input, result, tt.expected)
+			}
+		})
+	}
+}
+
+func TestGenerateBranchName(t *testing.T) {
+	now := time.Date(2025, 1, 31, 12, 0, 0, 0, time.UTC)
+	defaultTemplate := "{{.User}}/{{.Date}}{{with .Ticket}}-{{.}}{{end}}{{with .Slug}}-{{.}}{{end}}"
+
+	tests := []struct {
+		name        string
+		template    string
+		data        BranchNameData
+		expected    string
+		expectError bool
+	}{
+		{
+			name:     "user and date only",
+			template: defaultTemplate,
+			data:     NewBranchNameData("Alice", now, "", ""),
+			expected: "alice/2025-01-31",
+		},
+		{
+			name:     "with ticket and description",
+			template: defaultTemplate,
+			data:     NewBranchNameData("alice", now, "ABC-123", "Fix login bug"),
+			expected: "alice/2025-01-31-ABC-123-fix-login-bug",
+		},
+		{
+			name:     "custom template",
+			template: "exp/{{.Slug}}",
+			data:     NewBranchNameData("alice", now, "", "try new parser"),
+			expected: "exp/try-new-parser",
+		},
+		{
+			name:        "empty result",
+			template:    "{{.Slug}}",
+			data:        NewBranchNameData("alice", now, "", ""),
+			expectError: true,
+		},
+		{
+			name:        "invalid template",
+			template:    "{{.User",
+			data:        NewBranchNameData("alice", now, "", ""),
+			expectError: true,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			result, err := GenerateBranchName(tt.template, tt.data)
+			if tt.expectError {
+				if err == nil {
+					t.Errorf("GenerateBranchName() expected error, got %q", result)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("GenerateBranchName() returned error: %v", err)
+			}
+			if result != tt.expected {
+				t.Errorf("GenerateBranchName() = %q, want %q", result, tt.expected)
+			}
+		})
+	}
+}